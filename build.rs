@@ -153,6 +153,30 @@ fn main() {
     write_swizzles::<4, 4>(&mut out_file, "i", false).unwrap();
     writeln!(out_file, "}}").unwrap();
 
+    writeln!(out_file, "impl Vector2u {{").unwrap();
+    write_swizzles::<2, 2>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file).unwrap();
+    write_swizzles::<2, 3>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file).unwrap();
+    write_swizzles::<2, 4>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file, "}}").unwrap();
+
+    writeln!(out_file, "impl Vector3u {{").unwrap();
+    write_swizzles::<3, 2>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file).unwrap();
+    write_swizzles::<3, 3>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file).unwrap();
+    write_swizzles::<3, 4>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file, "}}").unwrap();
+
+    writeln!(out_file, "impl Vector4u {{").unwrap();
+    write_swizzles::<4, 2>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file).unwrap();
+    write_swizzles::<4, 3>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file).unwrap();
+    write_swizzles::<4, 4>(&mut out_file, "u", false).unwrap();
+    writeln!(out_file, "}}").unwrap();
+
     writeln!(out_file, "impl Quaternion {{").unwrap();
     write_swizzles::<4, 2>(&mut out_file, "f", false).unwrap();
     writeln!(out_file).unwrap();
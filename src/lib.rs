@@ -1,6 +1,7 @@
 //! Lightweight math library for game development
 
 #![feature(portable_simd)]
+#![cfg_attr(test, feature(test))]
 #![deny(missing_docs)]
 
 use std::fmt::Debug;
@@ -8,8 +9,9 @@ use std::fmt::Display;
 
 #[rustfmt::skip]
 use std::ops::{
-    Add, AddAssign, Div, DivAssign, Index, IndexMut,
-    Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign,
+    Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign,
+    Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
 use std::simd::Which::*;
@@ -34,7 +36,7 @@ macro_rules! simd_swizzle_2323 {
 }
 
 macro_rules! def_field {
-    ($name:ident, $name_mut:ident, $i:literal, $t:ty) => {
+    ($name:ident, $name_mut:ident, $name_with:ident, $i:literal, $t:ty) => {
         #[doc = concat!("The ", stringify!($name), " component of the vector")]
         #[inline]
         pub const fn $name(&self) -> $t {
@@ -46,9 +48,36 @@ macro_rules! def_field {
         pub fn $name_mut(&mut self) -> &mut $t {
             self.0.index_mut($i)
         }
+
+        #[doc = concat!("Returns a copy of this vector with the ", stringify!($name), " component replaced")]
+        #[inline]
+        pub const fn $name_with(self, $name: $t) -> Self {
+            let mut array = self.0.to_array();
+            array[$i] = $name;
+            Self(Simd::from_array(array))
+        }
     };
 }
 
+fn fmt_components<T: Display>(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    components: &[T],
+) -> std::fmt::Result {
+    write!(f, "{}(", name)?;
+    for (i, c) in components.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}", precision, c)?,
+            None => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, ")")
+}
+
 /// A vector with 2 f32 components
 #[derive(Clone, Copy, PartialEq)]
 #[repr(C, align(8))]
@@ -58,18 +87,24 @@ impl Vector2f {
     pub const ZERO: Self = Self::new(0.0, 0.0);
     /// The vector (1, 1)
     pub const ONE: Self = Self::new(1.0, 1.0);
+    /// The vector (inf, inf)
+    pub const INFINITY: Self = Self::new(f32::INFINITY, f32::INFINITY);
+    /// The vector (-inf, -inf)
+    pub const NEG_INFINITY: Self = Self::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+    /// The vector (NaN, NaN)
+    pub const NAN: Self = Self::new(f32::NAN, f32::NAN);
     /// The vector (1, 0)
     pub const UNIT_X: Self = Self::new(1.0, 0.0);
     /// The vector (0, 1)
     pub const UNIT_Y: Self = Self::new(0.0, 1.0);
 
-    def_field!(x, x_mut, 0, f32);
-    def_field!(y, y_mut, 1, f32);
+    def_field!(x, x_mut, with_x, 0, f32);
+    def_field!(y, y_mut, with_y, 1, f32);
 
     #[cfg(feature = "color_fields")]
-    def_field!(r, r_mut, 0, f32);
+    def_field!(r, r_mut, with_r, 0, f32);
     #[cfg(feature = "color_fields")]
-    def_field!(g, g_mut, 1, f32);
+    def_field!(g, g_mut, with_g, 1, f32);
 
     /// Creates a new vector from the given components
     #[inline]
@@ -89,6 +124,12 @@ impl Vector2f {
         Self(f32x2::from_array(array))
     }
 
+    /// Appends a Z component to this vector, same as `Vector3f::from_v2f`
+    #[inline]
+    pub const fn extend(self, z: f32) -> Vector3f {
+        Vector3f::from_v2f(self, z)
+    }
+
     /// Converts the vector into an array
     #[inline]
     pub const fn to_array(&self) -> [f32; 2] {
@@ -112,6 +153,11 @@ impl Vector2f {
         Self(simd_vec)
     }
 
+    #[inline]
+    const fn simd_with_padding(&self, _padding: f32) -> f32x2 {
+        self.0
+    }
+
     /// Calculates the cross product between this vector and rhs by setting the Z components to 0
     /// and returns the magnitude of the resulting vector
     #[inline]
@@ -119,19 +165,123 @@ impl Vector2f {
         let prod = self * rhs.yx();
         prod.0[0] - prod.0[1]
     }
+
+    /// Creates a unit vector pointing in the direction of the given angle, measured counter-clockwise from the X axis
+    #[inline]
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    /// Calculates the angle of this vector, measured counter-clockwise from the X axis, in the range [-pi, pi]
+    #[inline]
+    pub fn to_angle(self) -> f32 {
+        self.y().atan2(self.x())
+    }
+
+    /// Calculates the signed angle from this vector to rhs in the range (-pi, pi], positive angles are counter-clockwise
+    #[inline]
+    pub fn angle_to(self, rhs: Self) -> f32 {
+        // Adding 0 turns a cross product of -0 into +0, so opposite vectors always yield pi instead of -pi
+        (self.cross(rhs) + 0.0).atan2(self.dot(rhs))
+    }
+
+    /// Rotates this vector counter-clockwise by the given angle
+    #[inline]
+    pub fn rotate(self, angle: f32) -> Self {
+        self.rotate_by(Self::from_angle(angle))
+    }
+
+    /// Rotates this vector counter-clockwise by the angle of dir, which should be a unit vector
+    ///
+    /// This is a complex multiplication, so a dir created once by `from_angle` can be reused without recomputing sin and cos.
+    #[inline]
+    pub fn rotate_by(self, dir: Self) -> Self {
+        Self::new(
+            (self.x() * dir.x()) - (self.y() * dir.y()),
+            (self.x() * dir.y()) + (self.y() * dir.x()),
+        )
+    }
+
+    /// Returns this vector rotated 90 degrees counter-clockwise, (-y, x)
+    #[inline]
+    pub fn perp(self) -> Self {
+        Self(simd_swizzle!(self.0, [1, 0]) * f32x2::from_array([-1.0, 1.0]))
+    }
+
+    /// Returns this vector rotated 90 degrees clockwise, (y, -x)
+    #[inline]
+    pub fn perp_cw(self) -> Self {
+        Self(simd_swizzle!(self.0, [1, 0]) * f32x2::from_array([1.0, -1.0]))
+    }
+
+    /// Rotates this vector toward the direction of target by at most max_radians, preserving its length
+    ///
+    /// If target is within max_radians the result points exactly in its direction.
+    /// Opposite vectors are rotated counter-clockwise, a zero vector or target leaves this vector unchanged.
+    pub fn rotate_toward(self, target: Self, max_radians: f32) -> Self {
+        if (self.len2() == 0.0) || (target.len2() == 0.0) {
+            return self;
+        }
+
+        // adding 0 turns a cross product of -0 into +0, so opposite vectors yield pi instead of -pi
+        let delta = (self.cross(target) + 0.0).atan2(self.dot(target));
+        if delta.abs() <= max_radians {
+            return target.normalized() * self.len();
+        }
+
+        let angle = if delta < 0.0 {
+            -max_radians
+        } else {
+            max_radians
+        };
+        self.rotate(angle)
+    }
+
+    /// Calculates the barycentric coordinates of p with respect to the triangle (a, b, c)
+    ///
+    /// If the triangle has zero area the weights (1, 0, 0) are returned
+    pub fn barycentric(p: Self, a: Self, b: Self, c: Self) -> Vector3f {
+        let v0 = b - a;
+        let v1 = c - a;
+        let v2 = p - a;
+
+        let area = v0.cross(v1);
+        if area == 0.0 {
+            return Vector3f::UNIT_X;
+        }
+
+        let v = v2.cross(v1) / area;
+        let w = v0.cross(v2) / area;
+        Vector3f::new(1.0 - v - w, v, w)
+    }
+
+    /// Calculates the point with the given barycentric coordinates with respect to the triangle (a, b, c)
+    #[inline]
+    pub fn from_barycentric(weights: Vector3f, a: Self, b: Self, c: Self) -> Self {
+        (a * weights.x()) + (b * weights.y()) + (c * weights.z())
+    }
 }
 impl Debug for Vector2f {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vector2f({}, {})", self.x(), self.y())
+        fmt_components(f, "Vector2f", self.as_array())
     }
 }
 impl Display for Vector2f {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x(), self.y())
+        fmt_components(f, "", self.as_array())
     }
 }
 
 /// A vector with 3 f32 components
+///
+/// The vector is backed by 4 lanes, so it is 16 bytes in size and 16 byte aligned.
+/// The 4th lane is padding and always zero.
+///
+/// Casting a slice of vectors to bytes includes the padding lanes,
+/// use `write_packed` or `pack_into` to fill tightly packed buffers.
+/// Casting bytes into vectors requires the padding lanes to be zero,
+/// otherwise calculations like `len2` include them.
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
 pub struct Vector3f(f32x4);
@@ -140,6 +290,13 @@ impl Vector3f {
     pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
     /// The vector (1, 1, 1)
     pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+    /// The vector (inf, inf, inf)
+    pub const INFINITY: Self = Self::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+    /// The vector (-inf, -inf, -inf)
+    pub const NEG_INFINITY: Self =
+        Self::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY);
+    /// The vector (NaN, NaN, NaN)
+    pub const NAN: Self = Self::new(f32::NAN, f32::NAN, f32::NAN);
     /// The vector (1, 0, 0)
     pub const UNIT_X: Self = Self::new(1.0, 0.0, 0.0);
     /// The vector (0, 1, 0)
@@ -147,16 +304,16 @@ impl Vector3f {
     /// The vector (0, 0, 1)
     pub const UNIT_Z: Self = Self::new(0.0, 0.0, 1.0);
 
-    def_field!(x, x_mut, 0, f32);
-    def_field!(y, y_mut, 1, f32);
-    def_field!(z, z_mut, 2, f32);
+    def_field!(x, x_mut, with_x, 0, f32);
+    def_field!(y, y_mut, with_y, 1, f32);
+    def_field!(z, z_mut, with_z, 2, f32);
 
     #[cfg(feature = "color_fields")]
-    def_field!(r, r_mut, 0, f32);
+    def_field!(r, r_mut, with_r, 0, f32);
     #[cfg(feature = "color_fields")]
-    def_field!(g, g_mut, 1, f32);
+    def_field!(g, g_mut, with_g, 1, f32);
     #[cfg(feature = "color_fields")]
-    def_field!(b, b_mut, 2, f32);
+    def_field!(b, b_mut, with_b, 2, f32);
 
     /// Creates a new vector from the given components
     #[inline]
@@ -182,6 +339,18 @@ impl Vector3f {
         Self(f32x4::from_array([v.x(), v.y(), z, 0.0]))
     }
 
+    /// Drops the Z component of this vector, equivalent to `xy()`
+    #[inline]
+    pub fn truncate(self) -> Vector2f {
+        Vector2f(simd_swizzle!(self.0, [0, 1]))
+    }
+
+    /// Appends a W component to this vector, same as `Vector4f::from_v3f`
+    #[inline]
+    pub const fn extend(self, w: f32) -> Vector4f {
+        Vector4f::from_v3f(self, w)
+    }
+
     /// Converts the vector into an array
     #[inline]
     pub const fn to_array(&self) -> [f32; 3] {
@@ -189,6 +358,15 @@ impl Vector3f {
         [array[0], array[1], array[2]]
     }
 
+    /// Converts the vector into an array including the padding lane, which is zero (possibly -0.0)
+    /// unless the vector was cast from arbitrary bytes
+    ///
+    /// This matches the layout of a 16 byte aligned 3-component vector, like `vec3` in std140 buffers
+    #[inline]
+    pub const fn to_array4(&self) -> [f32; 4] {
+        self.0.to_array()
+    }
+
     /// Returns an array reference to the vector
     #[inline]
     pub const fn as_array(&self) -> &[f32; 3] {
@@ -203,6 +381,8 @@ impl Vector3f {
         unsafe { std::mem::transmute(a) }
     }
 
+    // Lane-wise operations like `recip` or `signum` turn the zero padding lane into a non-zero value,
+    // so their results must be passed through here to restore it before the vector is observable
     #[inline]
     fn from_simd_truncate(simd_vec: f32x4) -> Self {
         let zero = f32x4::splat(0.0);
@@ -210,6 +390,40 @@ impl Vector3f {
         Self(mask.select(simd_vec, zero))
     }
 
+    #[inline]
+    fn simd_with_padding(&self, padding: f32) -> f32x4 {
+        let padding = f32x4::splat(padding);
+        let mask = mask32x4::from_array([true, true, true, false]);
+        mask.select(self.0, padding)
+    }
+
+    /// Writes the 3 components of the vector to the start of out, without padding
+    ///
+    /// Panics if out is shorter than 3 elements
+    #[inline]
+    pub fn write_packed(&self, out: &mut [f32]) {
+        out[..3].copy_from_slice(self.as_array());
+    }
+
+    /// Reads a vector from the first 3 elements of input
+    ///
+    /// Panics if input is shorter than 3 elements
+    #[inline]
+    pub fn read_packed(input: &[f32]) -> Self {
+        Self::new(input[0], input[1], input[2])
+    }
+
+    /// Writes the components of all vectors in src into dst, 3 elements per vector without padding
+    ///
+    /// Panics if dst is shorter than 3 times the length of src
+    pub fn pack_into(src: &[Self], dst: &mut [f32]) {
+        assert!(dst.len() >= (src.len() * 3));
+
+        for (v, out) in src.iter().zip(dst.chunks_exact_mut(3)) {
+            v.write_packed(out);
+        }
+    }
+
     /// Calculates the cross product between this vector and rhs
     pub fn cross(self, rhs: Self) -> Self {
         // Algorithm from: https://geometrian.com/programming/tutorials/cross-product/index.php
@@ -221,15 +435,123 @@ impl Vector3f {
         let tmp4 = simd_swizzle!(tmp2, [1, 2, 0, 3]);
         Self(tmp3 - tmp4)
     }
+
+    /// Calculates the scalar triple product `self.dot(b.cross(c))`,
+    /// which is the signed volume of the parallelepiped spanned by the three vectors
+    #[inline]
+    pub fn scalar_triple(self, b: Self, c: Self) -> f32 {
+        self.dot(b.cross(c))
+    }
+
+    /// Calculates the vector triple product `self.cross(b.cross(c))`
+    #[inline]
+    pub fn vector_triple(self, b: Self, c: Self) -> Self {
+        self.cross(b.cross(c))
+    }
+
+    /// Returns the coordinates of the grid cell containing this point, for a grid of cubic cells with the given size
+    ///
+    /// Coordinates are floored, so negative points map to negative cells instead of being truncated towards cell 0.
+    /// Components outside the range of `i32` saturate.
+    ///
+    /// The cell size must be greater than zero, this is checked in debug builds.
+    /// Otherwise a size of 0 saturates all non-zero components and a negative size mirrors the grid.
+    #[inline]
+    pub fn quantize(self, cell_size: f32) -> Vector3i {
+        debug_assert!(cell_size > 0.0, "cell size {cell_size} is not positive");
+
+        Vector3i((self.0 / f32x4::splat(cell_size)).floor().cast())
+    }
+
+    /// Spherically interpolates between this vector and rhs
+    ///
+    /// Like `Quaternion::slerp` this always takes the shorter arc, the angle between the vectors is in the range [0, pi].
+    /// If the vectors are nearly parallel or opposite this falls back to linear interpolation.
+    pub fn slerp(self, rhs: Self, t: f32) -> Self {
+        let cos = self.normalized().dot(rhs.normalized()).clamp(-1.0, 1.0);
+        let omega = cos.acos();
+        let sin = omega.sin();
+
+        if sin > f32::EPSILON {
+            let scale1 = ((1.0 - t) * omega).sin() / sin;
+            let scale2 = (t * omega).sin() / sin;
+            (self * scale1) + (rhs * scale2)
+        } else {
+            self.lerp(rhs, t)
+        }
+    }
+
+    /// Rotates this vector toward the direction of target by at most max_radians, preserving its length
+    ///
+    /// The rotation happens around the axis `self.cross(target)`, if target is within max_radians the result points exactly in its direction.
+    /// Opposite vectors are rotated around an arbitrary perpendicular axis, a zero vector or target leaves this vector unchanged.
+    pub fn rotate_toward(self, target: Self, max_radians: f32) -> Self {
+        if (self.len2() == 0.0) || (target.len2() == 0.0) {
+            return self;
+        }
+
+        let axis = self.cross(target);
+        let angle = axis.len().atan2(self.dot(target));
+        if angle <= max_radians {
+            return target.normalized() * self.len();
+        }
+
+        let axis = if axis.len2() > f32::EPSILON * self.len2() * target.len2() {
+            axis.normalized()
+        } else {
+            // the vectors are opposite, any axis perpendicular to this vector works
+            let other = if self.x().abs() < (0.9 * self.len()) {
+                Self::UNIT_X
+            } else {
+                Self::UNIT_Y
+            };
+            self.cross(other).normalized()
+        };
+
+        // the axis is perpendicular to this vector, so Rodrigues' formula reduces to two terms
+        let (sin, cos) = max_radians.sin_cos();
+        (self * cos) + (axis.cross(self) * sin)
+    }
+
+    /// Calculates the barycentric coordinates of p with respect to the triangle (a, b, c),
+    /// after projecting p into the plane of the triangle
+    ///
+    /// If the triangle has zero area the weights (1, 0, 0) are returned
+    pub fn barycentric(p: Self, a: Self, b: Self, c: Self) -> Self {
+        let v0 = b - a;
+        let v1 = c - a;
+        let v2 = p - a;
+
+        let d00 = v0.dot(v0);
+        let d01 = v0.dot(v1);
+        let d11 = v1.dot(v1);
+        let d20 = v2.dot(v0);
+        let d21 = v2.dot(v1);
+
+        let denom = (d00 * d11) - (d01 * d01);
+        if denom == 0.0 {
+            return Self::UNIT_X;
+        }
+
+        let v = ((d11 * d20) - (d01 * d21)) / denom;
+        let w = ((d00 * d21) - (d01 * d20)) / denom;
+        Self::new(1.0 - v - w, v, w)
+    }
+
+    /// Calculates the point with the given barycentric coordinates with respect to the triangle (a, b, c)
+    #[inline]
+    pub fn from_barycentric(weights: Self, a: Self, b: Self, c: Self) -> Self {
+        (a * weights.x()) + (b * weights.y()) + (c * weights.z())
+    }
 }
 impl Debug for Vector3f {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vector3f({}, {}, {})", self.x(), self.y(), self.z())
+        fmt_components(f, "Vector3f", self.as_array())
     }
 }
 impl Display for Vector3f {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {}, {})", self.x(), self.y(), self.z())
+        fmt_components(f, "", self.as_array())
     }
 }
 impl PartialEq for Vector3f {
@@ -249,6 +571,18 @@ impl Vector4f {
     pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
     /// The vector (1, 1, 1, 1)
     pub const ONE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+    /// The vector (inf, inf, inf, inf)
+    pub const INFINITY: Self =
+        Self::new(f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::INFINITY);
+    /// The vector (-inf, -inf, -inf, -inf)
+    pub const NEG_INFINITY: Self = Self::new(
+        f32::NEG_INFINITY,
+        f32::NEG_INFINITY,
+        f32::NEG_INFINITY,
+        f32::NEG_INFINITY,
+    );
+    /// The vector (NaN, NaN, NaN, NaN)
+    pub const NAN: Self = Self::new(f32::NAN, f32::NAN, f32::NAN, f32::NAN);
     /// The vector (1, 0, 0, 0)
     pub const UNIT_X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
     /// The vector (0, 1, 0, 0)
@@ -258,19 +592,19 @@ impl Vector4f {
     /// The vector (0, 0, 0, 1)
     pub const UNIT_W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
 
-    def_field!(x, x_mut, 0, f32);
-    def_field!(y, y_mut, 1, f32);
-    def_field!(z, z_mut, 2, f32);
-    def_field!(w, w_mut, 3, f32);
+    def_field!(x, x_mut, with_x, 0, f32);
+    def_field!(y, y_mut, with_y, 1, f32);
+    def_field!(z, z_mut, with_z, 2, f32);
+    def_field!(w, w_mut, with_w, 3, f32);
 
     #[cfg(feature = "color_fields")]
-    def_field!(r, r_mut, 0, f32);
+    def_field!(r, r_mut, with_r, 0, f32);
     #[cfg(feature = "color_fields")]
-    def_field!(g, g_mut, 1, f32);
+    def_field!(g, g_mut, with_g, 1, f32);
     #[cfg(feature = "color_fields")]
-    def_field!(b, b_mut, 2, f32);
+    def_field!(b, b_mut, with_b, 2, f32);
     #[cfg(feature = "color_fields")]
-    def_field!(a, a_mut, 3, f32);
+    def_field!(a, a_mut, with_a, 3, f32);
 
     /// Creates a new vector from the given components
     #[inline]
@@ -324,29 +658,95 @@ impl Vector4f {
     const fn from_simd_truncate(simd_vec: f32x4) -> Self {
         Self(simd_vec)
     }
+
+    #[inline]
+    const fn simd_with_padding(&self, _padding: f32) -> f32x4 {
+        self.0
+    }
+
+    /// Calculates the dot product of the X, Y and Z components between this vector and rhs, ignoring W
+    #[inline]
+    pub fn dot3(self, rhs: Self) -> f32 {
+        let zero = f32x4::splat(0.0);
+        let mask = mask32x4::from_array([true, true, true, false]);
+        let prod = mask.select(self.0 * rhs.0, zero);
+        prod.reduce_sum()
+    }
+
+    /// Calculates the dot product between this vector and every vector in others, writing the results to out
+    ///
+    /// The vectors are processed in chunks of 4, so the horizontal sums can be computed with a single transpose.
+    /// Panics if out and others have different lengths.
+    pub fn dot_many(self, others: &[Vector4f], out: &mut [f32]) {
+        assert_eq!(
+            others.len(),
+            out.len(),
+            "output length must match the number of vectors"
+        );
+
+        let mut others_chunks = others.chunks_exact(4);
+        let mut out_chunks = out.chunks_exact_mut(4);
+        for (others, out) in (&mut others_chunks).zip(&mut out_chunks) {
+            let products = Matrix4x4([
+                self.0 * others[0].0,
+                self.0 * others[1].0,
+                self.0 * others[2].0,
+                self.0 * others[3].0,
+            ]);
+
+            // after transposing, column i holds component i of every product
+            let [c0, c1, c2, c3] = products.transposed().0;
+            let sums = (c0 + c1) + (c2 + c3);
+            out.copy_from_slice(sums.as_array());
+        }
+
+        for (other, out) in others_chunks
+            .remainder()
+            .iter()
+            .zip(out_chunks.into_remainder())
+        {
+            *out = self.dot(*other);
+        }
+    }
+
+    /// Drops the W component of this vector, equivalent to `xyz()`
+    ///
+    /// This is the reverse of `from_v3f`, the W component is discarded without dividing by it
+    #[inline]
+    pub fn truncate(self) -> Vector3f {
+        Vector3f::from_simd_truncate(self.0)
+    }
+
+    /// Packs the vector into 4 unsigned normalized 8 bit values, with x in the least significant byte
+    ///
+    /// Components are clamped to the range [0, 1]
+    pub fn pack_unorm8(self) -> u32 {
+        const SHIFT: u32x4 = u32x4::from_array([0, 8, 16, 24]);
+
+        let zero = f32x4::splat(0.0);
+        let one = f32x4::splat(1.0);
+        let scaled = (self.0.simd_clamp(zero, one) * f32x4::splat(255.0)).round();
+        let bytes: u32x4 = scaled.cast();
+        (bytes << SHIFT).reduce_or()
+    }
+
+    /// Unpacks a vector from 4 unsigned normalized 8 bit values, with x in the least significant byte
+    pub fn unpack_unorm8(packed: u32) -> Self {
+        const SHIFT: u32x4 = u32x4::from_array([0, 8, 16, 24]);
+
+        let bytes = (u32x4::splat(packed) >> SHIFT) & u32x4::splat(0xFF);
+        let unorm: f32x4 = bytes.cast();
+        Self(unorm / f32x4::splat(255.0))
+    }
 }
 impl Debug for Vector4f {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Vector4f({}, {}, {}, {})",
-            self.x(),
-            self.y(),
-            self.z(),
-            self.w()
-        )
+        fmt_components(f, "Vector4f", self.as_array())
     }
 }
 impl Display for Vector4f {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "({}, {}, {}, {})",
-            self.x(),
-            self.y(),
-            self.z(),
-            self.w()
-        )
+        fmt_components(f, "", self.as_array())
     }
 }
 
@@ -396,6 +796,30 @@ macro_rules! impl_common_f {
                 Self::dot(self, self)
             }
 
+            /// Calculates the dot product between this vector and rhs, usable in constant expressions
+            ///
+            /// Prefer `dot` outside of constant expressions
+            pub const fn dot_const(self, rhs: Self) -> f32 {
+                let lhs = self.0.as_array();
+                let rhs = rhs.0.as_array();
+
+                let mut sum = 0.0;
+                let mut i = 0;
+                while i < lhs.len() {
+                    sum += lhs[i] * rhs[i];
+                    i += 1;
+                }
+                sum
+            }
+
+            /// The length of this vector squared, usable in constant expressions
+            ///
+            /// Prefer `len2` outside of constant expressions
+            #[inline]
+            pub const fn len2_const(self) -> f32 {
+                Self::dot_const(self, self)
+            }
+
             /// The length of this vector
             #[inline]
             pub fn len(self) -> f32 {
@@ -403,6 +827,8 @@ macro_rules! impl_common_f {
             }
 
             /// Normalizes the vector
+            ///
+            /// A zero vector is returned unchanged, use `try_normalize` or `normalize_or_zero` to handle degenerate vectors explicitly.
             #[inline]
             pub fn normalized(self) -> Self {
                 let len = self.len();
@@ -413,12 +839,86 @@ macro_rules! impl_common_f {
                 }
             }
 
+            /// Normalizes the vector, returning `None` if its length is below `f32::EPSILON` or not finite
+            #[inline]
+            pub fn try_normalize(self) -> Option<Self> {
+                let len = self.len();
+                if len.is_finite() && (len >= f32::EPSILON) {
+                    Some(self / len)
+                } else {
+                    None
+                }
+            }
+
+            /// Normalizes the vector, returning the zero vector if its length is below `f32::EPSILON` or not finite
+            #[inline]
+            pub fn normalize_or_zero(self) -> Self {
+                self.try_normalize().unwrap_or(Self::ZERO)
+            }
+
+            /// Normalizes the vector in place, returning whether that succeeded
+            ///
+            /// Fails under the same conditions as `try_normalize`, in which case the vector is left unchanged and false is returned
+            #[inline]
+            pub fn normalize_in_place(&mut self) -> bool {
+                match self.try_normalize() {
+                    Some(normalized) => {
+                        *self = normalized;
+                        true
+                    }
+                    None => false,
+                }
+            }
+
             /// Linearily interpolates between this vector and rhs
             #[inline]
             pub fn lerp(self, rhs: Self, t: f32) -> Self {
                 self + ((rhs - self) * t)
             }
 
+            /// Linearily interpolates between this vector and rhs, clamping t to the range [0, 1]
+            #[inline]
+            pub fn lerp_clamped(self, rhs: Self, t: f32) -> Self {
+                self.lerp(rhs, t.clamp(0.0, 1.0))
+            }
+
+            /// Calculates for each component where this vector lies between a and b,
+            /// such that `a.lerp(b, t)` results in this vector
+            ///
+            /// Components in which a and b are equal result in an infinite or NaN value
+            #[inline]
+            pub fn inverse_lerp(self, a: Self, b: Self) -> Self {
+                (self - a) / (b - a)
+            }
+
+            /// Reflects this vector off a surface with the given normal
+            ///
+            /// The normal must be normalized
+            #[inline]
+            pub fn reflect(self, normal: Self) -> Self {
+                self - (normal * (2.0 * self.dot(normal)))
+            }
+
+            /// Refracts this vector through a surface with the given normal, using Snell's law
+            ///
+            /// eta is the ratio of the refractive indices on the incoming and outgoing side.
+            /// Both this vector and the normal must be normalized, the zero vector is returned on total internal reflection.
+            pub fn refract(self, normal: Self, eta: f32) -> Self {
+                let cos = self.dot(normal);
+                let k = 1.0 - (eta * eta * (1.0 - (cos * cos)));
+                if k < 0.0 {
+                    Self::ZERO
+                } else {
+                    (self * eta) - (normal * ((eta * cos) + k.sqrt()))
+                }
+            }
+
+            /// Calculates the point halfway between this vector and rhs
+            #[inline]
+            pub fn midpoint(self, rhs: Self) -> Self {
+                (self + rhs) * 0.5
+            }
+
             /// Calculates the distance between this vector and rhs squared
             #[inline]
             pub fn dist2(self, b: Self) -> f32 {
@@ -431,6 +931,57 @@ macro_rules! impl_common_f {
                 (b - self).len()
             }
 
+            /// Calculates the point on the infinite line through a and b that is closest to p
+            ///
+            /// If a and b are equal, a is returned
+            pub fn closest_point_on_line(p: Self, a: Self, b: Self) -> Self {
+                let ab = b - a;
+                let len2 = ab.len2();
+                if len2 == 0.0 {
+                    a
+                } else {
+                    a + (ab * ((p - a).dot(ab) / len2))
+                }
+            }
+
+            /// Calculates the distance between p and the infinite line through a and b
+            #[inline]
+            pub fn distance_to_line(p: Self, a: Self, b: Self) -> f32 {
+                p.dist(Self::closest_point_on_line(p, a, b))
+            }
+
+            /// Calculates the point on the line segment between a and b that is closest to p
+            ///
+            /// If a and b are equal, a is returned
+            pub fn closest_point_on_segment(p: Self, a: Self, b: Self) -> Self {
+                let ab = b - a;
+                let len2 = ab.len2();
+                if len2 == 0.0 {
+                    a
+                } else {
+                    let t = ((p - a).dot(ab) / len2).clamp(0.0, 1.0);
+                    a + (ab * t)
+                }
+            }
+
+            /// Calculates the distance between p and the line segment between a and b
+            #[inline]
+            pub fn distance_to_segment(p: Self, a: Self, b: Self) -> f32 {
+                p.dist(Self::closest_point_on_segment(p, a, b))
+            }
+
+            /// Calculates the Manhattan (L1) distance between this vector and rhs
+            #[inline]
+            pub fn distance_manhattan(self, rhs: Self) -> f32 {
+                (rhs - self).abs().element_sum()
+            }
+
+            /// Calculates the Chebyshev (L-infinity) distance between this vector and rhs
+            #[inline]
+            pub fn distance_chebyshev(self, rhs: Self) -> f32 {
+                (rhs - self).abs().max_element()
+            }
+
             /// Returns a vector with each component set to the minimum of the corresponding components between this vector and rhs
             #[inline]
             pub fn min(self, rhs: Self) -> Self {
@@ -443,41 +994,357 @@ macro_rules! impl_common_f {
                 Self(<$ts>::simd_max(self.0, rhs.0))
             }
 
+            /// Returns a vector with the magnitude of each component of this vector and the sign of the corresponding component in sign
+            #[inline]
+            pub fn copysign(self, sign: Self) -> Self {
+                Self(self.0.copysign(sign.0))
+            }
+
+            /// Returns a vector with each component set to 1 or -1 depending on the sign of the corresponding component in this vector
+            ///
+            /// Like `f32::signum`, zero components result in 1 or -1 as well, use `signum_zero` to map them to 0
+            #[inline]
+            pub fn signum(self) -> Self {
+                Self::from_simd_truncate(self.0.signum())
+            }
+
+            /// Returns a vector with each component set to 1, 0 or -1 depending on the sign of the corresponding component in this vector
+            #[inline]
+            pub fn signum_zero(self) -> Self {
+                let zero = <$ts>::splat(0.0);
+                let mask = self.0.simd_eq(zero);
+                Self(mask.select(zero, self.0.signum()))
+            }
+
+            /// Returns a vector with each component set to the sine of the corresponding component in this vector
+            #[inline]
+            pub fn sin(self) -> Self {
+                self.map(f32::sin)
+            }
+
+            /// Returns a vector with each component set to the cosine of the corresponding component in this vector
+            #[inline]
+            pub fn cos(self) -> Self {
+                self.map(f32::cos)
+            }
+
+            /// Returns a vector with each component set to the tangent of the corresponding component in this vector
+            #[inline]
+            pub fn tan(self) -> Self {
+                self.map(f32::tan)
+            }
+
             /// Calculates (self * a) + b in one operation
             #[inline]
             pub fn mul_add(self, a: Self, b: Self) -> Self {
                 Self(<$ts>::mul_add(self.0, a.0, b.0))
             }
-        }
-    };
-}
 
-impl_common_f!(Vector2f, f32x2);
-impl_common_f!(Vector3f, f32x4);
-impl_common_f!(Vector4f, f32x4);
+            /// Calculates the Euclidean quotient of each component of this vector and the corresponding component of rhs
+            ///
+            /// This matches `f32::div_euclid` for every component
+            pub fn div_euclid(self, rhs: Self) -> Self {
+                let q = (self.0 / rhs.0).trunc();
+                let r = self.0 % rhs.0;
+                let mask = r.simd_lt(<$ts>::splat(0.0));
+                Self::from_simd_truncate(mask.select(q - rhs.0.signum(), q))
+            }
 
-/// A vector with 2 i32 components
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(C, align(8))]
-pub struct Vector2i(i32x2);
-impl Vector2i {
-    /// The vector (0, 0)
-    pub const ZERO: Self = Self::new(0, 0);
+            /// Calculates the Euclidean quotient of each component of this vector and rhs
+            #[inline]
+            pub fn div_euclid_scalar(self, rhs: f32) -> Self {
+                self.div_euclid(Self::from_scalar(rhs))
+            }
 
-    def_field!(x, x_mut, 0, i32);
-    def_field!(y, y_mut, 1, i32);
+            /// Calculates the least non-negative remainder of each component of this vector and the corresponding component of rhs
+            ///
+            /// This matches `f32::rem_euclid` for every component, which is useful for wrapping values into the range [0, rhs)
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                let r = self.0 % rhs.0;
+                let mask = r.simd_lt(<$ts>::splat(0.0));
+                Self::from_simd_truncate(mask.select(r + rhs.0.abs(), r))
+            }
 
-    /// Creates a new vector from the given components
-    #[inline]
-    pub const fn new(x: i32, y: i32) -> Self {
-        Self(i32x2::from_array([x, y]))
-    }
+            /// Calculates the least non-negative remainder of each component of this vector and rhs
+            #[inline]
+            pub fn rem_euclid_scalar(self, rhs: f32) -> Self {
+                self.rem_euclid(Self::from_scalar(rhs))
+            }
 
-    /// Creates a new vector by setting all components to the given scalar
-    #[inline]
-    pub const fn from_scalar(scalar: i32) -> Self {
-        Self(i32x2::from_array([scalar; 2]))
-    }
+            /// Checks whether this vector is equal to other, up to a certain error per component
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, epsilon: f32) -> bool {
+                // the padding lanes of both vectors are zero, so they always compare equal
+                let d = (self.0 - other.0).abs();
+                d.simd_le(<$ts>::splat(epsilon)).all()
+            }
+
+            /// Returns a vector with f applied to each component of this vector
+            #[inline]
+            pub fn map(self, f: impl Fn(f32) -> f32) -> Self {
+                Self::from_array(self.to_array().map(f))
+            }
+
+            /// Returns a vector with f applied to each pair of corresponding components of this vector and rhs
+            #[inline]
+            pub fn zip_map(self, rhs: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+                let lhs = self.to_array();
+                let rhs = rhs.to_array();
+                Self::from_array(std::array::from_fn(|i| f(lhs[i], rhs[i])))
+            }
+
+            /// Returns a vector with each component clamped to the range given by the corresponding components of min and max
+            ///
+            /// Unlike `f32::clamp` this does not panic if min is greater than max, the result is unspecified in that case
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self(<$ts>::simd_min(<$ts>::simd_max(self.0, min.0), max.0))
+            }
+
+            /// Returns the component-wise minimum of three vectors
+            #[inline]
+            pub fn min3(a: Self, b: Self, c: Self) -> Self {
+                a.min(b).min(c)
+            }
+
+            /// Returns the component-wise maximum of three vectors
+            #[inline]
+            pub fn max3(a: Self, b: Self, c: Self) -> Self {
+                a.max(b).max(c)
+            }
+
+            /// Returns the component-wise minimum of all vectors in the slice, or `None` if the slice is empty
+            #[inline]
+            pub fn min_of(vectors: &[Self]) -> Option<Self> {
+                vectors.iter().copied().reduce(Self::min)
+            }
+
+            /// Returns the component-wise maximum of all vectors in the slice, or `None` if the slice is empty
+            #[inline]
+            pub fn max_of(vectors: &[Self]) -> Option<Self> {
+                vectors.iter().copied().reduce(Self::max)
+            }
+
+            /// Returns the smallest component of this vector
+            #[inline]
+            pub fn min_element(self) -> f32 {
+                self.simd_with_padding(f32::INFINITY).reduce_min()
+            }
+
+            /// Returns the largest component of this vector
+            #[inline]
+            pub fn max_element(self) -> f32 {
+                self.simd_with_padding(f32::NEG_INFINITY).reduce_max()
+            }
+
+            /// Returns the sum of all components of this vector
+            #[inline]
+            pub fn element_sum(self) -> f32 {
+                self.simd_with_padding(0.0).reduce_sum()
+            }
+
+            /// Returns the product of all components of this vector
+            #[inline]
+            pub fn element_product(self) -> f32 {
+                self.simd_with_padding(1.0).reduce_product()
+            }
+
+            /// Returns the index of the smallest component of this vector, preferring the lower index on ties
+            pub fn min_axis(self) -> usize {
+                let array = self.as_array();
+                let mut axis = 0;
+                for (i, &c) in array.iter().enumerate().skip(1) {
+                    if c < array[axis] {
+                        axis = i;
+                    }
+                }
+                axis
+            }
+
+            /// Returns the index of the largest component of this vector, preferring the lower index on ties
+            pub fn max_axis(self) -> usize {
+                let array = self.as_array();
+                let mut axis = 0;
+                for (i, &c) in array.iter().enumerate().skip(1) {
+                    if c > array[axis] {
+                        axis = i;
+                    }
+                }
+                axis
+            }
+        }
+    };
+}
+
+impl_common_f!(Vector2f, f32x2);
+impl_common_f!(Vector3f, f32x4);
+impl_common_f!(Vector4f, f32x4);
+
+/// Calculates the angle of every vector in src like `Vector2f::to_angle`, writing the results to out
+///
+/// Panics if src and out have different lengths
+pub fn angles(src: &[Vector2f], out: &mut [f32]) {
+    assert_eq!(
+        src.len(),
+        out.len(),
+        "output length must match the number of vectors"
+    );
+
+    for (v, out) in src.iter().zip(out) {
+        *out = v.to_angle();
+    }
+}
+
+/// Calculates the average of the given 2-component points
+///
+/// Returns the zero vector if the slice is empty
+pub fn centroid_2d(points: &[Vector2f]) -> Vector2f {
+    if points.is_empty() {
+        return Vector2f::ZERO;
+    }
+
+    points.iter().sum::<Vector2f>() / (points.len() as f32)
+}
+
+/// Calculates the average of the given 3-component points
+///
+/// Returns the zero vector if the slice is empty
+pub fn centroid(points: &[Vector3f]) -> Vector3f {
+    if points.is_empty() {
+        return Vector3f::ZERO;
+    }
+
+    points.iter().sum::<Vector3f>() / (points.len() as f32)
+}
+
+/// Calculates the Schlick approximation of the Fresnel reflectance
+///
+/// cos_theta is clamped to the range [0, 1]
+pub fn fresnel_schlick(cos_theta: f32, f0: Vector3f) -> Vector3f {
+    let k = 1.0 - cos_theta.clamp(0.0, 1.0);
+    let k2 = k * k;
+    let k5 = k2 * k2 * k;
+    f0 + ((Vector3f::ONE - f0) * k5)
+}
+
+#[cfg(feature = "color_fields")]
+#[inline]
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        (1.055 * c.powf(1.0 / 2.4)) - 0.055
+    }
+}
+
+#[cfg(feature = "color_fields")]
+#[inline]
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[cfg(feature = "color_fields")]
+impl Vector3f {
+    /// Converts a linear RGB color into sRGB space
+    pub fn linear_to_srgb(self) -> Self {
+        Self::from_array(self.to_array().map(linear_to_srgb))
+    }
+
+    /// Converts an sRGB color into linear RGB space
+    pub fn srgb_to_linear(self) -> Self {
+        Self::from_array(self.to_array().map(srgb_to_linear))
+    }
+
+    /// Converts an RGB color with components in the range [0, 1] into HSV,
+    /// with hue, saturation and value all in the range [0, 1]
+    ///
+    /// Gray colors have a hue and saturation of 0
+    pub fn rgb_to_hsv(self) -> Self {
+        let [r, g, b] = self.to_array();
+        let max = self.max_element();
+        let min = self.min_element();
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            ((g - b) / delta).rem_euclid(6.0) / 6.0
+        } else if max == g {
+            (((b - r) / delta) + 2.0) / 6.0
+        } else {
+            (((r - g) / delta) + 4.0) / 6.0
+        };
+
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        Self::new(h, s, max)
+    }
+
+    /// Converts an HSV color with hue, saturation and value in the range [0, 1] into RGB
+    ///
+    /// Hue wraps around, so values outside of [0, 1] are valid
+    pub fn hsv_to_rgb(self) -> Self {
+        let [h, s, v] = self.to_array();
+        let h = h.rem_euclid(1.0) * 6.0;
+        let sector = h.floor();
+        let f = h - sector;
+
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - (s * f));
+        let t = v * (1.0 - (s * (1.0 - f)));
+
+        match sector as u32 {
+            0 => Self::new(v, t, p),
+            1 => Self::new(q, v, p),
+            2 => Self::new(p, v, t),
+            3 => Self::new(p, q, v),
+            4 => Self::new(t, p, v),
+            _ => Self::new(v, p, q),
+        }
+    }
+}
+
+#[cfg(feature = "color_fields")]
+impl Vector4f {
+    /// Converts a linear RGBA color into sRGB space, leaving alpha untouched
+    pub fn linear_to_srgb(self) -> Self {
+        Self::from_v3f(self.xyz().linear_to_srgb(), self.w())
+    }
+
+    /// Converts an sRGB color into linear RGBA space, leaving alpha untouched
+    pub fn srgb_to_linear(self) -> Self {
+        Self::from_v3f(self.xyz().srgb_to_linear(), self.w())
+    }
+}
+
+/// A vector with 2 i32 components
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C, align(8))]
+pub struct Vector2i(i32x2);
+impl Vector2i {
+    /// The vector (0, 0)
+    pub const ZERO: Self = Self::new(0, 0);
+    /// The vector (1, 1)
+    pub const ONE: Self = Self::new(1, 1);
+
+    def_field!(x, x_mut, with_x, 0, i32);
+    def_field!(y, y_mut, with_y, 1, i32);
+
+    /// Creates a new vector from the given components
+    #[inline]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self(i32x2::from_array([x, y]))
+    }
+
+    /// Creates a new vector by setting all components to the given scalar
+    #[inline]
+    pub const fn from_scalar(scalar: i32) -> Self {
+        Self(i32x2::from_array([scalar; 2]))
+    }
 
     /// Creates a new vector from the given array
     #[inline]
@@ -485,6 +1352,21 @@ impl Vector2i {
         Self(i32x2::from_array(array))
     }
 
+    /// Appends a Z component to this vector, same as `Vector3i::from_v2i`
+    #[inline]
+    pub const fn extend(self, z: i32) -> Vector3i {
+        Vector3i::from_v2i(self, z)
+    }
+
+    /// Calculates the cross product between this vector and rhs by setting the Z components to 0
+    /// and returns the Z component of the resulting vector
+    ///
+    /// The result is widened to `i64` and never overflows.
+    #[inline]
+    pub fn cross(self, rhs: Self) -> i64 {
+        ((self.x() as i64) * (rhs.y() as i64)) - ((self.y() as i64) * (rhs.x() as i64))
+    }
+
     /// Converts the vector into an array
     #[inline]
     pub const fn to_array(&self) -> [i32; 2] {
@@ -513,29 +1395,70 @@ impl Vector2i {
     const fn from_simd_truncate(simd_vec: i32x2) -> Self {
         Self(simd_vec)
     }
+
+    #[inline]
+    const fn simd_with_padding(&self, _padding: i32) -> i32x2 {
+        self.0
+    }
+
+    /// Returns the 4 orthogonally adjacent cells, in counter-clockwise order starting at +X
+    pub fn neighbors4(self) -> [Self; 4] {
+        const OFFSETS: [Vector2i; 4] = [
+            Vector2i::new(1, 0),
+            Vector2i::new(0, 1),
+            Vector2i::new(-1, 0),
+            Vector2i::new(0, -1),
+        ];
+
+        OFFSETS.map(|offset| self + offset)
+    }
+
+    /// Returns the 8 orthogonally and diagonally adjacent cells, in counter-clockwise order starting at +X
+    pub fn neighbors8(self) -> [Self; 8] {
+        const OFFSETS: [Vector2i; 8] = [
+            Vector2i::new(1, 0),
+            Vector2i::new(1, 1),
+            Vector2i::new(0, 1),
+            Vector2i::new(-1, 1),
+            Vector2i::new(-1, 0),
+            Vector2i::new(-1, -1),
+            Vector2i::new(0, -1),
+            Vector2i::new(1, -1),
+        ];
+
+        OFFSETS.map(|offset| self + offset)
+    }
 }
 impl Debug for Vector2i {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vector2i({}, {})", self.x(), self.y())
+        fmt_components(f, "Vector2i", self.as_array())
     }
 }
 impl Display for Vector2i {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x(), self.y())
+        fmt_components(f, "", self.as_array())
     }
 }
 
 /// A vector with 3 i32 components
+///
+/// The vector is backed by 4 lanes, so it is 16 bytes in size and 16 byte aligned.
+/// The 4th lane is padding and always zero.
+///
+/// Casting bytes into vectors requires the padding lanes to be zero,
+/// otherwise calculations like `len2` include them.
 #[derive(Clone, Copy)]
 #[repr(C, align(16))]
 pub struct Vector3i(i32x4);
 impl Vector3i {
     /// The vector (0, 0, 0)
     pub const ZERO: Self = Self::new(0, 0, 0);
+    /// The vector (1, 1, 1)
+    pub const ONE: Self = Self::new(1, 1, 1);
 
-    def_field!(x, x_mut, 0, i32);
-    def_field!(y, y_mut, 1, i32);
-    def_field!(z, z_mut, 2, i32);
+    def_field!(x, x_mut, with_x, 0, i32);
+    def_field!(y, y_mut, with_y, 1, i32);
+    def_field!(z, z_mut, with_z, 2, i32);
 
     /// Creates a new vector from the given components
     #[inline]
@@ -561,6 +1484,30 @@ impl Vector3i {
         Self(i32x4::from_array([v.x(), v.y(), z, 0]))
     }
 
+    /// Drops the Z component of this vector, equivalent to `xy()`
+    #[inline]
+    pub fn truncate(self) -> Vector2i {
+        Vector2i(simd_swizzle!(self.0, [0, 1]))
+    }
+
+    /// Appends a W component to this vector, same as `Vector4i::from_v3i`
+    #[inline]
+    pub const fn extend(self, w: i32) -> Vector4i {
+        Vector4i::from_v3i(self, w)
+    }
+
+    /// Calculates the cross product between this vector and rhs
+    ///
+    /// The calculation wraps on overflow, which cannot happen as long as all components are in the range [-32767, 32767].
+    pub fn cross(self, rhs: Self) -> Self {
+        let tmp0 = simd_swizzle!(self.0, [1, 2, 0, 3]);
+        let tmp1 = simd_swizzle!(rhs.0, [2, 0, 1, 3]);
+        let tmp2 = tmp0 * rhs.0;
+        let tmp3 = tmp0 * tmp1;
+        let tmp4 = simd_swizzle!(tmp2, [1, 2, 0, 3]);
+        Self(tmp3 - tmp4)
+    }
+
     /// Converts the vector into an array
     #[inline]
     pub const fn to_array(&self) -> [i32; 3] {
@@ -568,6 +1515,15 @@ impl Vector3i {
         [array[0], array[1], array[2]]
     }
 
+    /// Converts the vector into an array including the padding lane, which is 0
+    /// unless the vector was cast from arbitrary bytes
+    ///
+    /// This matches the layout of a 16 byte aligned 3-component vector, like `vec3` in std140 buffers
+    #[inline]
+    pub const fn to_array4(&self) -> [i32; 4] {
+        self.0.to_array()
+    }
+
     /// Casts this vector into a floating point vector
     #[inline]
     pub fn to_float(&self) -> Vector3f {
@@ -594,15 +1550,55 @@ impl Vector3i {
         let mask = mask32x4::from_array([true, true, true, false]);
         Self(mask.select(simd_vec, zero))
     }
+
+    #[inline]
+    fn simd_with_padding(&self, padding: i32) -> i32x4 {
+        let padding = i32x4::splat(padding);
+        let mask = mask32x4::from_array([true, true, true, false]);
+        mask.select(self.0, padding)
+    }
+
+    /// Returns the 6 face-adjacent cells, in the order +X, -X, +Y, -Y, +Z, -Z
+    pub fn neighbors6(self) -> [Self; 6] {
+        const OFFSETS: [Vector3i; 6] = [
+            Vector3i::new(1, 0, 0),
+            Vector3i::new(-1, 0, 0),
+            Vector3i::new(0, 1, 0),
+            Vector3i::new(0, -1, 0),
+            Vector3i::new(0, 0, 1),
+            Vector3i::new(0, 0, -1),
+        ];
+
+        OFFSETS.map(|offset| self + offset)
+    }
+
+    /// Returns the 26 face-, edge- and corner-adjacent cells, ordered by Z, then Y, then X offset
+    pub fn neighbors26(self) -> [Self; 26] {
+        let mut neighbors = [self; 26];
+
+        let mut i = 0;
+        for z in -1..=1 {
+            for y in -1..=1 {
+                for x in -1..=1 {
+                    if (x, y, z) != (0, 0, 0) {
+                        neighbors[i] += Self::new(x, y, z);
+                        i += 1;
+                    }
+                }
+            }
+        }
+
+        neighbors
+    }
 }
 impl Debug for Vector3i {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vector3i({}, {}, {})", self.x(), self.y(), self.z())
+        fmt_components(f, "Vector3i", self.as_array())
     }
 }
 impl Display for Vector3i {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {}, {})", self.x(), self.y(), self.z())
+        fmt_components(f, "", self.as_array())
     }
 }
 impl PartialEq for Vector3i {
@@ -628,11 +1624,13 @@ pub struct Vector4i(i32x4);
 impl Vector4i {
     /// The vector (0, 0, 0, 0)
     pub const ZERO: Self = Self::new(0, 0, 0, 0);
+    /// The vector (1, 1, 1, 1)
+    pub const ONE: Self = Self::new(1, 1, 1, 1);
 
-    def_field!(x, x_mut, 0, i32);
-    def_field!(y, y_mut, 1, i32);
-    def_field!(z, z_mut, 2, i32);
-    def_field!(w, w_mut, 3, i32);
+    def_field!(x, x_mut, with_x, 0, i32);
+    def_field!(y, y_mut, with_y, 1, i32);
+    def_field!(z, z_mut, with_z, 2, i32);
+    def_field!(w, w_mut, with_w, 3, i32);
 
     /// Creates a new vector from the given components
     #[inline]
@@ -664,6 +1662,14 @@ impl Vector4i {
         Self(i32x4::from_array([v.x(), v.y(), v.z(), w]))
     }
 
+    /// Drops the W component of this vector, equivalent to `xyz()`
+    ///
+    /// This is the reverse of `from_v3i`, the W component is discarded without dividing by it
+    #[inline]
+    pub fn truncate(self) -> Vector3i {
+        Vector3i::from_simd_truncate(self.0)
+    }
+
     /// Converts the vector into an array
     #[inline]
     pub const fn to_array(&self) -> [i32; 4] {
@@ -692,29 +1698,20 @@ impl Vector4i {
     const fn from_simd_truncate(simd_vec: i32x4) -> Self {
         Self(simd_vec)
     }
+
+    #[inline]
+    const fn simd_with_padding(&self, _padding: i32) -> i32x4 {
+        self.0
+    }
 }
 impl Debug for Vector4i {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Vector4i({}, {}, {}, {})",
-            self.x(),
-            self.y(),
-            self.z(),
-            self.w()
-        )
+        fmt_components(f, "Vector4i", self.as_array())
     }
 }
 impl Display for Vector4i {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "({}, {}, {}, {})",
-            self.x(),
-            self.y(),
-            self.z(),
-            self.w()
-        )
+        fmt_components(f, "", self.as_array())
     }
 }
 
@@ -727,6 +1724,56 @@ macro_rules! impl_common_i {
                 Self(self.0.abs())
             }
 
+            /// Returns a vector with each component set to the absolute difference of the corresponding components between this vector and rhs,
+            /// wrapping if the difference exceeds `i32::MAX`
+            #[inline]
+            pub fn abs_diff(self, rhs: Self) -> Self {
+                Self(<$ts>::simd_max(self.0, rhs.0) - <$ts>::simd_min(self.0, rhs.0))
+            }
+
+            /// Calculates the greatest common divisor of all components of this vector
+            ///
+            /// The result is zero only if all components are zero. It is unsigned because
+            /// the greatest common divisor of `i32::MIN` and 0 does not fit into an `i32`.
+            pub fn component_gcd(self) -> u32 {
+                let mut gcd = 0u32;
+                for &c in self.as_array() {
+                    let mut a = gcd;
+                    let mut b = c.unsigned_abs();
+                    while b != 0 {
+                        (a, b) = (b, a % b);
+                    }
+                    gcd = a;
+                }
+                gcd
+            }
+
+            /// Returns a vector with each component raised to the given power, wrapping on overflow
+            #[inline]
+            pub fn pow(self, exp: u32) -> Self {
+                Self::from_array(self.to_array().map(|c| c.wrapping_pow(exp)))
+            }
+
+            /// Calculates the dot product between this vector and rhs
+            ///
+            /// The products are widened to `i64`, so the result cannot overflow as long as all components are in the range [-2^30, 2^30].
+            #[inline]
+            pub fn dot(self, rhs: Self) -> i64 {
+                (self.0.cast::<i64>() * rhs.0.cast::<i64>()).reduce_sum()
+            }
+
+            /// Calculates the Manhattan (L1) distance between this vector and rhs
+            #[inline]
+            pub fn distance_manhattan(self, rhs: Self) -> i32 {
+                (rhs - self).abs().element_sum()
+            }
+
+            /// Calculates the Chebyshev (L-infinity) distance between this vector and rhs
+            #[inline]
+            pub fn distance_chebyshev(self, rhs: Self) -> i32 {
+                (rhs - self).abs().max_element()
+            }
+
             /// Returns a vector with each component set to the minimum of the corresponding components between this vector and rhs
             #[inline]
             pub fn min(self, rhs: Self) -> Self {
@@ -738,683 +1785,3033 @@ macro_rules! impl_common_i {
             pub fn max(self, rhs: Self) -> Self {
                 Self(<$ts>::simd_max(self.0, rhs.0))
             }
-        }
-    };
-}
 
-impl_common_i!(Vector2i, i32x2);
-impl_common_i!(Vector3i, i32x4);
-impl_common_i!(Vector4i, i32x4);
-
-macro_rules! impl_operators {
-    ($t:ty, $ts:ty, $ti:ty) => {
-        impl Add for $t {
-            type Output = Self;
+            /// Linearily interpolates between this vector and rhs, rounding each component to the nearest integer
+            ///
+            /// Halfway values are rounded away from zero, like `f32::round`
+            #[inline]
+            pub fn lerp(self, rhs: Self, t: f32) -> Self {
+                let lerped = self.to_float().lerp(rhs.to_float(), t);
+                Self(lerped.0.round().cast())
+            }
 
-            fn add(self, rhs: Self) -> Self::Output {
-                Self(self.0 + rhs.0)
+            /// Returns a vector with f applied to each component of this vector
+            #[inline]
+            pub fn map(self, f: impl Fn(i32) -> i32) -> Self {
+                Self::from_array(self.to_array().map(f))
             }
-        }
-        impl AddAssign for $t {
-            fn add_assign(&mut self, rhs: Self) {
-                *self = *self + rhs;
+
+            /// Returns a vector with f applied to each pair of corresponding components of this vector and rhs
+            #[inline]
+            pub fn zip_map(self, rhs: Self, f: impl Fn(i32, i32) -> i32) -> Self {
+                let lhs = self.to_array();
+                let rhs = rhs.to_array();
+                Self::from_array(std::array::from_fn(|i| f(lhs[i], rhs[i])))
             }
-        }
-        impl Sub for $t {
-            type Output = Self;
 
-            fn sub(self, rhs: Self) -> Self::Output {
-                Self(self.0 - rhs.0)
+            /// Returns a vector with each component clamped to the range given by the corresponding components of min and max
+            ///
+            /// Unlike `f32::clamp` this does not panic if min is greater than max, the result is unspecified in that case
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self(<$ts>::simd_min(<$ts>::simd_max(self.0, min.0), max.0))
             }
-        }
-        impl SubAssign for $t {
-            fn sub_assign(&mut self, rhs: Self) {
-                *self = *self - rhs;
+
+            /// Returns the component-wise minimum of three vectors
+            #[inline]
+            pub fn min3(a: Self, b: Self, c: Self) -> Self {
+                a.min(b).min(c)
             }
-        }
-        impl Neg for $t {
-            type Output = Self;
 
-            fn neg(self) -> Self::Output {
-                Self(-self.0)
+            /// Returns the component-wise maximum of three vectors
+            #[inline]
+            pub fn max3(a: Self, b: Self, c: Self) -> Self {
+                a.max(b).max(c)
             }
-        }
-        impl Mul for $t {
-            type Output = Self;
 
-            fn mul(self, rhs: Self) -> Self::Output {
-                Self(self.0 * rhs.0)
+            /// Returns the component-wise minimum of all vectors in the slice, or `None` if the slice is empty
+            #[inline]
+            pub fn min_of(vectors: &[Self]) -> Option<Self> {
+                vectors.iter().copied().reduce(Self::min)
             }
-        }
-        impl MulAssign for $t {
-            fn mul_assign(&mut self, rhs: Self) {
-                *self = *self * rhs;
+
+            /// Returns the component-wise maximum of all vectors in the slice, or `None` if the slice is empty
+            #[inline]
+            pub fn max_of(vectors: &[Self]) -> Option<Self> {
+                vectors.iter().copied().reduce(Self::max)
             }
-        }
-        impl Div for $t {
-            type Output = Self;
 
-            fn div(self, rhs: Self) -> Self::Output {
-                Self::from_simd_truncate(self.0 / rhs.0)
+            /// Returns the smallest component of this vector
+            #[inline]
+            pub fn min_element(self) -> i32 {
+                self.simd_with_padding(i32::MAX).reduce_min()
             }
-        }
-        impl DivAssign for $t {
-            fn div_assign(&mut self, rhs: Self) {
-                *self = *self / rhs;
-            }
-        }
-        impl Rem for $t {
-            type Output = Self;
-
-            fn rem(self, rhs: Self) -> Self::Output {
-                Self::from_simd_truncate(self.0 % rhs.0)
-            }
-        }
-        impl RemAssign for $t {
-            fn rem_assign(&mut self, rhs: Self) {
-                *self = *self % rhs;
-            }
-        }
-        impl Add<$ti> for $t {
-            type Output = Self;
-
-            fn add(self, rhs: $ti) -> Self::Output {
-                Self::from_simd_truncate(self.0 + <$ts>::splat(rhs))
-            }
-        }
-        impl AddAssign<$ti> for $t {
-            fn add_assign(&mut self, rhs: $ti) {
-                *self = *self + rhs;
-            }
-        }
-        impl Sub<$ti> for $t {
-            type Output = Self;
 
-            fn sub(self, rhs: $ti) -> Self::Output {
-                Self::from_simd_truncate(self.0 - <$ts>::splat(rhs))
-            }
-        }
-        impl SubAssign<$ti> for $t {
-            fn sub_assign(&mut self, rhs: $ti) {
-                *self = *self - rhs;
+            /// Returns the largest component of this vector
+            #[inline]
+            pub fn max_element(self) -> i32 {
+                self.simd_with_padding(i32::MIN).reduce_max()
             }
-        }
-        impl Mul<$ti> for $t {
-            type Output = Self;
 
-            fn mul(self, rhs: $ti) -> Self::Output {
-                Self::from_simd_truncate(self.0 * <$ts>::splat(rhs))
-            }
-        }
-        impl MulAssign<$ti> for $t {
-            fn mul_assign(&mut self, rhs: $ti) {
-                *self = *self * rhs;
+            /// Returns the sum of all components of this vector, wrapping on overflow
+            #[inline]
+            pub fn element_sum(self) -> i32 {
+                self.simd_with_padding(0).reduce_sum()
             }
-        }
-        impl Div<$ti> for $t {
-            type Output = Self;
 
-            fn div(self, rhs: $ti) -> Self::Output {
-                Self::from_simd_truncate(self.0 / <$ts>::splat(rhs))
-            }
-        }
-        impl DivAssign<$ti> for $t {
-            fn div_assign(&mut self, rhs: $ti) {
-                *self = *self / rhs;
+            /// Returns the product of all components of this vector, wrapping on overflow
+            #[inline]
+            pub fn element_product(self) -> i32 {
+                self.simd_with_padding(1).reduce_product()
             }
-        }
-        impl Rem<$ti> for $t {
-            type Output = Self;
 
-            fn rem(self, rhs: $ti) -> Self::Output {
-                Self::from_simd_truncate(self.0 % <$ts>::splat(rhs))
-            }
-        }
-        impl RemAssign<$ti> for $t {
-            fn rem_assign(&mut self, rhs: $ti) {
-                *self = *self % rhs;
+            /// Returns the index of the smallest component of this vector, preferring the lower index on ties
+            pub fn min_axis(self) -> usize {
+                let array = self.as_array();
+                let mut axis = 0;
+                for (i, &c) in array.iter().enumerate().skip(1) {
+                    if c < array[axis] {
+                        axis = i;
+                    }
+                }
+                axis
             }
-        }
-        impl Index<usize> for $t {
-            type Output = $ti;
 
-            fn index(&self, index: usize) -> &Self::Output {
-                self.0.index(index)
-            }
-        }
-        impl IndexMut<usize> for $t {
-            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                self.0.index_mut(index)
+            /// Returns the index of the largest component of this vector, preferring the lower index on ties
+            pub fn max_axis(self) -> usize {
+                let array = self.as_array();
+                let mut axis = 0;
+                for (i, &c) in array.iter().enumerate().skip(1) {
+                    if c > array[axis] {
+                        axis = i;
+                    }
+                }
+                axis
             }
         }
     };
 }
 
-impl_operators!(Vector2f, f32x2, f32);
-impl_operators!(Vector3f, f32x4, f32);
-impl_operators!(Vector4f, f32x4, f32);
-impl_operators!(Vector2i, i32x2, i32);
-impl_operators!(Vector3i, i32x4, i32);
-impl_operators!(Vector4i, i32x4, i32);
-
-macro_rules! def_quat_field {
-    ($name:ident, $name_mut:ident, $i:literal, $t:ty) => {
-        #[doc = concat!("The ", stringify!($name), " component of the quaternion")]
-        #[inline]
-        pub const fn $name(&self) -> $t {
-            self.0.as_array()[$i]
-        }
-
-        #[doc = concat!("The ", stringify!($name), " component of the quaternion")]
-        #[inline]
-        pub fn $name_mut(&mut self) -> &mut $t {
-            self.0.index_mut($i)
-        }
-    };
-}
+impl_common_i!(Vector2i, i32x2);
+impl_common_i!(Vector3i, i32x4);
+impl_common_i!(Vector4i, i32x4);
 
-/// A quaternion
-#[derive(Clone, Copy, PartialEq)]
-#[repr(C, align(16))]
-pub struct Quaternion(f32x4);
-impl Quaternion {
-    /// A quaternion representing no rotation
-    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+/// A vector with 2 u32 components
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C, align(8))]
+pub struct Vector2u(u32x2);
+impl Vector2u {
+    /// The vector (0, 0)
+    pub const ZERO: Self = Self::new(0, 0);
+    /// The vector (1, 1)
+    pub const ONE: Self = Self::new(1, 1);
 
-    def_quat_field!(x, x_mut, 0, f32);
-    def_quat_field!(y, y_mut, 1, f32);
-    def_quat_field!(z, z_mut, 2, f32);
-    def_quat_field!(w, w_mut, 3, f32);
+    def_field!(x, x_mut, with_x, 0, u32);
+    def_field!(y, y_mut, with_y, 1, u32);
 
-    /// Creates a new quaternion from the given components
+    /// Creates a new vector from the given components
     #[inline]
-    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
-        Self(f32x4::from_array([x, y, z, w]))
+    pub const fn new(x: u32, y: u32) -> Self {
+        Self(u32x2::from_array([x, y]))
     }
 
-    /// Creates a new quaternion from the given array
+    /// Creates a new vector by setting all components to the given scalar
     #[inline]
-    pub const fn from_array(array: [f32; 4]) -> Self {
-        Self(f32x4::from_array(array))
+    pub const fn from_scalar(scalar: u32) -> Self {
+        Self(u32x2::from_array([scalar; 2]))
     }
 
-    /// Converts the quaternion into an array
+    /// Creates a new vector from the given array
     #[inline]
-    pub const fn to_array(&self) -> [f32; 4] {
-        self.0.to_array()
+    pub const fn from_array(array: [u32; 2]) -> Self {
+        Self(u32x2::from_array(array))
     }
 
-    /// Returns an array reference to the quaternion
+    /// Converts the vector into an array
     #[inline]
-    pub const fn as_array(&self) -> &[f32; 4] {
-        self.0.as_array()
+    pub const fn to_array(&self) -> [u32; 2] {
+        self.0.to_array()
     }
 
-    /// Returns a mutable array reference to the quaternion
+    /// Casts this vector into a signed integer vector, components above `i32::MAX` wrap around to negative values
     #[inline]
-    pub fn as_mut_array(&mut self) -> &mut [f32; 4] {
-        self.0.as_mut_array()
+    pub fn to_int(&self) -> Vector2i {
+        Vector2i(self.0.cast())
     }
 
-    /// Creates a quaternion representing a rotation around an arbitrary axis
-    ///
-    /// The axis vector must be normalized
-    pub fn from_axis_angle(axis: Vector3f, angle: f32) -> Self {
-        let (sin, cos) = (angle * 0.5).sin_cos();
-        Self::new(axis.x() * sin, axis.y() * sin, axis.z() * sin, cos)
+    /// Returns an array reference to the vector
+    #[inline]
+    pub const fn as_array(&self) -> &[u32; 2] {
+        self.0.as_array()
     }
 
-    /// Creates a quaternion representing a rotation around the X axis
-    pub fn from_angle_x(angle: f32) -> Self {
-        let (sin, cos) = (angle * 0.5).sin_cos();
-        Self::new(sin, 0.0, 0.0, cos)
+    /// Returns a mutable array reference to the vector
+    #[inline]
+    pub fn as_mut_array(&mut self) -> &mut [u32; 2] {
+        self.0.as_mut_array()
     }
 
-    /// Creates a quaternion representing a rotation around the Y axis
-    pub fn from_angle_y(angle: f32) -> Self {
-        let (sin, cos) = (angle * 0.5).sin_cos();
-        Self::new(0.0, sin, 0.0, cos)
+    #[inline]
+    const fn from_simd_truncate(simd_vec: u32x2) -> Self {
+        Self(simd_vec)
     }
-
-    /// Creates a quaternion representing a rotation around the Z axis
-    pub fn from_angle_z(angle: f32) -> Self {
-        let (sin, cos) = (angle * 0.5).sin_cos();
-        Self::new(0.0, 0.0, sin, cos)
+}
+impl Debug for Vector2u {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_components(f, "Vector2u", self.as_array())
     }
-
-    /// Creates a quaternion representing a rotation specified by yaw, pitch and roll angles
-    pub fn from_yaw_pitch_roll(yaw: f32, pitch: f32, roll: f32) -> Self {
-        let y = Self::from_angle_y(yaw);
-        let x = Self::from_angle_x(pitch);
-        let z = Self::from_angle_z(roll);
-        y * x * z
+}
+impl Display for Vector2u {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_components(f, "", self.as_array())
     }
+}
 
-    /// Converts the quaternion into an equivalent rotation around an axis
-    pub fn to_axis_angle(&self) -> (Vector3f, f32) {
-        let q = if self.w() > 1.0 {
-            self.normalized()
-        } else {
-            *self
-        };
+/// A vector with 3 u32 components
+///
+/// Like `Vector3i` this is backed by a 4 lane SIMD vector, the padding lane is always zero
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+pub struct Vector3u(u32x4);
+impl Vector3u {
+    /// The vector (0, 0, 0)
+    pub const ZERO: Self = Self::new(0, 0, 0);
+    /// The vector (1, 1, 1)
+    pub const ONE: Self = Self::new(1, 1, 1);
 
-        let angle = 2.0 * q.w().acos();
+    def_field!(x, x_mut, with_x, 0, u32);
+    def_field!(y, y_mut, with_y, 1, u32);
+    def_field!(z, z_mut, with_z, 2, u32);
 
-        let s = (1.0 - (q.w() * q.w())).sqrt();
-        if s < f32::EPSILON {
-            (Vector3f::new(1.0, 0.0, 0.0), angle)
-        } else {
-            let x = q.x() / s;
-            let y = q.y() / s;
-            let z = q.z() / s;
+    /// Creates a new vector from the given components
+    #[inline]
+    pub const fn new(x: u32, y: u32, z: u32) -> Self {
+        Self(u32x4::from_array([x, y, z, 0]))
+    }
 
-            (Vector3f::new(x, y, z), angle)
-        }
+    /// Creates a new vector by setting all components to the given scalar
+    #[inline]
+    pub const fn from_scalar(scalar: u32) -> Self {
+        Self(u32x4::from_array([scalar, scalar, scalar, 0]))
     }
 
-    /// Normalizes the quaternion
+    /// Creates a new vector from the given array
     #[inline]
-    pub fn normalized(self) -> Self {
-        let len = self.xyzw().len();
-        if len == 0.0 {
-            self
-        } else {
-            self * (1.0 / len)
-        }
+    pub const fn from_array(array: [u32; 3]) -> Self {
+        Self(u32x4::from_array([array[0], array[1], array[2], 0]))
     }
 
-    /// Returns the conjugate of this quaternion
+    /// Converts the vector into an array
     #[inline]
-    pub fn conjugate(self) -> Self {
-        Self::new(-self.x(), -self.y(), -self.z(), self.w())
+    pub const fn to_array(&self) -> [u32; 3] {
+        let array: [u32; 4] = self.0.to_array();
+        [array[0], array[1], array[2]]
     }
 
-    /// Returns the inverse of this quaternion
+    /// Converts the vector into an array including the padding lane, which is 0
+    /// unless the vector was cast from arbitrary bytes
     #[inline]
-    pub fn inverse(self) -> Self {
-        self.conjugate() * (1.0 / self.xyzw().len2())
+    pub const fn to_array4(&self) -> [u32; 4] {
+        self.0.to_array()
     }
 
-    /// Linearily interpolates between this quaternion and rhs
-    pub fn lerp(self, rhs: Self, t: f32) -> Self {
-        if self.xyzw().dot(rhs.xyzw()) < 0.0 {
-            self - ((rhs + self) * t)
-        } else {
-            self + ((rhs - self) * t)
-        }
-        .normalized()
+    /// Casts this vector into a signed integer vector, components above `i32::MAX` wrap around to negative values
+    #[inline]
+    pub fn to_int(&self) -> Vector3i {
+        Vector3i(self.0.cast())
     }
 
-    /// Spherically interpolates between this quaternion and rhs
-    pub fn slerp(self, rhs: Self, t: f32) -> Self {
-        let temp: Self;
-        let mut cosom = self.xyzw().dot(rhs.xyzw());
+    /// Returns an array reference to the vector
+    #[inline]
+    pub const fn as_array(&self) -> &[u32; 3] {
+        let a: &[u32; 4] = self.0.as_array();
+        unsafe { std::mem::transmute(a) }
+    }
 
-        if cosom < 0.0 {
-            temp = -rhs;
-            cosom = -cosom;
-        } else {
-            temp = rhs;
-        }
+    /// Returns a mutable array reference to the vector
+    #[inline]
+    pub fn as_mut_array(&mut self) -> &mut [u32; 3] {
+        let a: &mut [u32; 4] = self.0.as_mut_array();
+        unsafe { std::mem::transmute(a) }
+    }
 
-        let scale1: f32;
-        let scale2: f32;
-        if (1.0 - cosom) > f32::EPSILON {
-            let omega = cosom.acos();
-            let sinom = 1.0 / omega.sin();
-            scale1 = ((1.0 - t) * omega).sin() * sinom;
-            scale2 = (t * omega).sin() * sinom;
-        } else {
-            scale1 = 1.0 - t;
-            scale2 = t;
-        }
-
-        ((self * scale1) + (temp * scale2)).normalized()
+    #[inline]
+    fn from_simd_truncate(simd_vec: u32x4) -> Self {
+        let zero = u32x4::splat(0);
+        let mask = mask32x4::from_array([true, true, true, false]);
+        Self(mask.select(simd_vec, zero))
     }
 }
-impl Debug for Quaternion {
+impl Debug for Vector3u {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Quaternion({}, {}, {}, {})",
-            self.x(),
-            self.y(),
-            self.z(),
-            self.w()
-        )
+        fmt_components(f, "Vector3u", self.as_array())
     }
 }
-impl Display for Quaternion {
+impl Display for Vector3u {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "({}, {}, {}, {})",
-            self.x(),
-            self.y(),
-            self.z(),
-            self.w()
-        )
+        fmt_components(f, "", self.as_array())
     }
 }
-impl Index<usize> for Quaternion {
-    type Output = f32;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+impl PartialEq for Vector3u {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0.as_array()[0] == other.0.as_array()[0])
+            && (self.0.as_array()[1] == other.0.as_array()[1])
+            && (self.0.as_array()[2] == other.0.as_array()[2])
     }
 }
-impl IndexMut<usize> for Quaternion {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+impl Eq for Vector3u {}
+impl std::hash::Hash for Vector3u {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0[0].hash(state);
+        self.0[1].hash(state);
+        self.0[2].hash(state);
     }
 }
-impl Add for Quaternion {
-    type Output = Self;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
+/// A vector with 4 u32 components
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C, align(16))]
+pub struct Vector4u(u32x4);
+impl Vector4u {
+    /// The vector (0, 0, 0, 0)
+    pub const ZERO: Self = Self::new(0, 0, 0, 0);
+    /// The vector (1, 1, 1, 1)
+    pub const ONE: Self = Self::new(1, 1, 1, 1);
+
+    def_field!(x, x_mut, with_x, 0, u32);
+    def_field!(y, y_mut, with_y, 1, u32);
+    def_field!(z, z_mut, with_z, 2, u32);
+    def_field!(w, w_mut, with_w, 3, u32);
+
+    /// Creates a new vector from the given components
+    #[inline]
+    pub const fn new(x: u32, y: u32, z: u32, w: u32) -> Self {
+        Self(u32x4::from_array([x, y, z, w]))
     }
-}
-impl AddAssign for Quaternion {
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+
+    /// Creates a new vector by setting all components to the given scalar
+    #[inline]
+    pub const fn from_scalar(scalar: u32) -> Self {
+        Self(u32x4::from_array([scalar; 4]))
     }
-}
-impl Sub for Quaternion {
-    type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
+    /// Creates a new vector from the given array
+    #[inline]
+    pub const fn from_array(array: [u32; 4]) -> Self {
+        Self(u32x4::from_array(array))
     }
-}
-impl SubAssign for Quaternion {
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+
+    /// Converts the vector into an array
+    #[inline]
+    pub const fn to_array(&self) -> [u32; 4] {
+        self.0.to_array()
     }
-}
-impl Neg for Quaternion {
-    type Output = Self;
 
-    fn neg(self) -> Self::Output {
-        Self(-self.0)
+    /// Returns an array reference to the vector
+    #[inline]
+    pub const fn as_array(&self) -> &[u32; 4] {
+        self.0.as_array()
     }
-}
-impl Mul<f32> for Quaternion {
-    type Output = Self;
 
-    fn mul(self, rhs: f32) -> Self::Output {
-        Self(self.0 * f32x4::splat(rhs))
+    /// Casts this vector into a signed integer vector, components above `i32::MAX` wrap around to negative values
+    #[inline]
+    pub fn to_int(&self) -> Vector4i {
+        Vector4i(self.0.cast())
     }
-}
-impl MulAssign<f32> for Quaternion {
-    fn mul_assign(&mut self, rhs: f32) {
-        *self = *self * rhs;
+
+    /// Returns a mutable array reference to the vector
+    #[inline]
+    pub fn as_mut_array(&mut self) -> &mut [u32; 4] {
+        self.0.as_mut_array()
     }
-}
-impl Div<f32> for Quaternion {
-    type Output = Self;
 
-    fn div(self, rhs: f32) -> Self::Output {
-        Self(self.0 / f32x4::splat(rhs))
+    #[inline]
+    const fn from_simd_truncate(simd_vec: u32x4) -> Self {
+        Self(simd_vec)
     }
 }
-impl DivAssign<f32> for Quaternion {
-    fn div_assign(&mut self, rhs: f32) {
-        *self = *self / rhs;
+impl Debug for Vector4u {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_components(f, "Vector4u", self.as_array())
     }
 }
-impl Mul for Quaternion {
-    type Output = Self;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        let xyz = (rhs.xyz() * self.w())
-            + (self.xyz() * rhs.w())
-            + Vector3f::cross(self.xyz(), rhs.xyz());
-        let w = (self.w() * rhs.w()) - Vector3f::dot(self.xyz(), rhs.xyz());
-        Self::new(xyz.x(), xyz.y(), xyz.z(), w)
+impl Display for Vector4u {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_components(f, "", self.as_array())
     }
 }
-impl MulAssign for Quaternion {
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = *self * rhs;
+impl From<Vector4f> for Vector4u {
+    /// Reinterprets the bits of the components
+    fn from(v: Vector4f) -> Self {
+        Self(v.0.to_bits())
     }
 }
-impl Mul<Vector3f> for Quaternion {
-    type Output = Vector3f;
-
-    fn mul(self, rhs: Vector3f) -> Self::Output {
-        rhs + Vector3f::cross(
-            self.xyz(),
-            Vector3f::cross(self.xyz(), rhs) + (rhs * self.w()),
-        ) * 2.0
+impl From<Vector4u> for Vector4f {
+    /// Reinterprets the bits of the components
+    fn from(v: Vector4u) -> Self {
+        Self(f32x4::from_bits(v.0))
     }
 }
 
-macro_rules! impl_to_array {
-    ($t:ty, $ts:ty, $n:literal) => {
-        impl From<[$ts; $n]> for $t {
-            fn from(a: [$ts; $n]) -> Self {
-                Self::from_array(a)
+macro_rules! impl_common_u {
+    ($t:ty, $ts:ty, $ti:ty) => {
+        impl $t {
+            /// Returns a vector with each component set to the minimum of the corresponding components between this vector and rhs
+            #[inline]
+            pub fn min(self, rhs: Self) -> Self {
+                Self(<$ts>::simd_min(self.0, rhs.0))
             }
-        }
 
-        impl Into<[$ts; $n]> for $t {
-            fn into(self) -> [$ts; $n] {
-                self.to_array()
+            /// Returns a vector with each component set to the maximum of the corresponding components between this vector and rhs
+            #[inline]
+            pub fn max(self, rhs: Self) -> Self {
+                Self(<$ts>::simd_max(self.0, rhs.0))
             }
         }
 
-        impl AsRef<[$ts; $n]> for $t {
-            fn as_ref(&self) -> &[$ts; $n] {
-                self.as_array()
+        impl From<$ti> for $t {
+            /// Casts the components, negative components wrap around like `i32 as u32`
+            #[inline]
+            fn from(v: $ti) -> Self {
+                Self(v.0.cast())
             }
         }
+    };
+}
 
-        impl AsMut<[$ts; $n]> for $t {
-            fn as_mut(&mut self) -> &mut [$ts; $n] {
-                self.as_mut_array()
+impl_common_u!(Vector2u, u32x2, Vector2i);
+impl_common_u!(Vector3u, u32x4, Vector3i);
+impl_common_u!(Vector4u, u32x4, Vector4i);
+
+macro_rules! impl_bit_operators {
+    ($t:ty, $ts:ty, $ti:ty) => {
+        impl BitAnd for $t {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self(self.0 & rhs.0)
+            }
+        }
+        impl BitAndAssign for $t {
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
             }
         }
+        impl BitOr for $t {
+            type Output = Self;
 
-        impl std::borrow::Borrow<[$ts; $n]> for $t {
-            fn borrow(&self) -> &[$ts; $n] {
-                self.as_array()
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(self.0 | rhs.0)
+            }
+        }
+        impl BitOrAssign for $t {
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
             }
         }
+        impl BitXor for $t {
+            type Output = Self;
 
-        impl std::borrow::BorrowMut<[$ts; $n]> for $t {
-            fn borrow_mut(&mut self) -> &mut [$ts; $n] {
-                self.as_mut_array()
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self(self.0 ^ rhs.0)
             }
         }
-    };
-}
+        impl BitXorAssign for $t {
+            fn bitxor_assign(&mut self, rhs: Self) {
+                *self = *self ^ rhs;
+            }
+        }
+        impl Not for $t {
+            type Output = Self;
 
-impl_to_array!(Vector2f, f32, 2);
-impl_to_array!(Vector3f, f32, 3);
-impl_to_array!(Vector4f, f32, 4);
-impl_to_array!(Vector2i, i32, 2);
-impl_to_array!(Vector3i, i32, 3);
-impl_to_array!(Vector4i, i32, 4);
-impl_to_array!(Quaternion, f32, 4);
+            fn not(self) -> Self::Output {
+                Self::from_simd_truncate(!self.0)
+            }
+        }
+        impl Shl<$ti> for $t {
+            type Output = Self;
 
-macro_rules! format_width {
-    ($value:expr) => {{
-        let s = format!("{:+}", $value);
-        let w = s.chars().count();
-        (s, w)
-    }};
-}
+            fn shl(self, rhs: $ti) -> Self::Output {
+                Self(self.0 << <$ts>::splat(rhs))
+            }
+        }
+        impl ShlAssign<$ti> for $t {
+            fn shl_assign(&mut self, rhs: $ti) {
+                *self = *self << rhs;
+            }
+        }
+        impl Shr<$ti> for $t {
+            type Output = Self;
 
-/// Column-major 2x3 matrix, indexed as [row, column]
-#[derive(Clone, Copy, PartialEq)]
-#[repr(C, align(8))]
-pub struct Matrix2x3([f32x2; 3]);
-impl Matrix2x3 {
-    /// A matrix representing no transformation
-    pub const IDENTITY: Self = Self([
-        f32x2::from_array([1.0, 0.0]),
-        f32x2::from_array([0.0, 1.0]),
-        f32x2::from_array([0.0, 0.0]),
-    ]);
+            fn shr(self, rhs: $ti) -> Self::Output {
+                Self(self.0 >> <$ts>::splat(rhs))
+            }
+        }
+        impl ShrAssign<$ti> for $t {
+            fn shr_assign(&mut self, rhs: $ti) {
+                *self = *self >> rhs;
+            }
+        }
+        impl Index<usize> for $t {
+            type Output = $ti;
 
-    /// Creates a new matrix from individual elements
-    #[rustfmt::skip]
-    pub const fn new(
-        e00: f32, e10: f32, // Column 0
-        e01: f32, e11: f32, // Column 1
-        e02: f32, e12: f32, // Column 2
-    ) -> Self {
-        Self([
-            f32x2::from_array([e00, e10]),
-            f32x2::from_array([e01, e11]),
-            f32x2::from_array([e02, e12]),
-        ])
-    }
+            fn index(&self, index: usize) -> &Self::Output {
+                self.as_array().index(index)
+            }
+        }
+        impl IndexMut<usize> for $t {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                self.as_mut_array().index_mut(index)
+            }
+        }
+    };
+}
 
-    /// Creates a new matrix from the given array
-    #[inline]
-    pub const fn from_array(array: [[f32; 2]; 3]) -> Self {
-        Self([
-            f32x2::from_array(array[0]),
-            f32x2::from_array(array[1]),
-            f32x2::from_array(array[2]),
-        ])
-    }
+impl_bit_operators!(Vector2u, u32x2, u32);
+impl_bit_operators!(Vector3u, u32x4, u32);
+impl_bit_operators!(Vector4u, u32x4, u32);
 
-    /// Converts the matrix into an array
-    #[inline]
-    pub const fn to_array(&self) -> [[f32; 2]; 3] {
-        [
-            self.0[0].to_array(),
-            self.0[1].to_array(),
-            self.0[2].to_array(),
-        ]
-    }
+macro_rules! impl_operators {
+    ($t:ty, $ts:ty, $ti:ty) => {
+        impl Add for $t {
+            type Output = Self;
 
-    #[inline]
-    const fn column(&self, index: usize) -> f32x2 {
-        self.0[index]
-    }
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+        impl Sub for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+        impl Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+        impl Mul for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self(self.0 * rhs.0)
+            }
+        }
+        impl MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+        impl Div for $t {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                Self::from_simd_truncate(self.0 / rhs.0)
+            }
+        }
+        impl DivAssign for $t {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+        impl Rem for $t {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self::Output {
+                Self::from_simd_truncate(self.0 % rhs.0)
+            }
+        }
+        impl RemAssign for $t {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+        impl Add<$ti> for $t {
+            type Output = Self;
+
+            fn add(self, rhs: $ti) -> Self::Output {
+                Self::from_simd_truncate(self.0 + <$ts>::splat(rhs))
+            }
+        }
+        impl AddAssign<$ti> for $t {
+            fn add_assign(&mut self, rhs: $ti) {
+                *self = *self + rhs;
+            }
+        }
+        impl Sub<$ti> for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: $ti) -> Self::Output {
+                Self::from_simd_truncate(self.0 - <$ts>::splat(rhs))
+            }
+        }
+        impl SubAssign<$ti> for $t {
+            fn sub_assign(&mut self, rhs: $ti) {
+                *self = *self - rhs;
+            }
+        }
+        impl Mul<$ti> for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: $ti) -> Self::Output {
+                Self::from_simd_truncate(self.0 * <$ts>::splat(rhs))
+            }
+        }
+        impl MulAssign<$ti> for $t {
+            fn mul_assign(&mut self, rhs: $ti) {
+                *self = *self * rhs;
+            }
+        }
+        impl Div<$ti> for $t {
+            type Output = Self;
+
+            fn div(self, rhs: $ti) -> Self::Output {
+                Self::from_simd_truncate(self.0 / <$ts>::splat(rhs))
+            }
+        }
+        impl DivAssign<$ti> for $t {
+            fn div_assign(&mut self, rhs: $ti) {
+                *self = *self / rhs;
+            }
+        }
+        impl Rem<$ti> for $t {
+            type Output = Self;
+
+            fn rem(self, rhs: $ti) -> Self::Output {
+                Self::from_simd_truncate(self.0 % <$ts>::splat(rhs))
+            }
+        }
+        impl RemAssign<$ti> for $t {
+            fn rem_assign(&mut self, rhs: $ti) {
+                *self = *self % rhs;
+            }
+        }
+        impl Index<usize> for $t {
+            type Output = $ti;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                self.as_array().index(index)
+            }
+        }
+        impl IndexMut<usize> for $t {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                self.as_mut_array().index_mut(index)
+            }
+        }
+    };
+}
+
+impl_operators!(Vector2f, f32x2, f32);
+impl_operators!(Vector3f, f32x4, f32);
+impl_operators!(Vector4f, f32x4, f32);
+impl_operators!(Vector2i, i32x2, i32);
+impl_operators!(Vector3i, i32x4, i32);
+impl_operators!(Vector4i, i32x4, i32);
+
+/// A vector with 2 boolean components, produced by the component-wise comparisons of 2-component vectors
+#[derive(Clone, Copy, PartialEq)]
+pub struct Vector2b(mask32x2);
+impl Vector2b {
+    /// Creates a new vector from the given components
+    #[inline]
+    pub fn new(x: bool, y: bool) -> Self {
+        Self(mask32x2::from_array([x, y]))
+    }
+
+    /// Creates a new vector from the given array
+    #[inline]
+    pub fn from_array(array: [bool; 2]) -> Self {
+        Self(mask32x2::from_array(array))
+    }
+
+    /// Converts the vector into an array
+    #[inline]
+    pub fn to_array(&self) -> [bool; 2] {
+        self.0.to_array()
+    }
+
+    #[inline]
+    fn from_simd_truncate(simd_mask: mask32x2) -> Self {
+        Self(simd_mask)
+    }
+
+    #[inline]
+    fn simd_with_padding(&self, _padding: bool) -> mask32x2 {
+        self.0
+    }
+}
+
+/// A vector with 3 boolean components, produced by the component-wise comparisons of 3-component vectors
+///
+/// The padding lane is always false
+#[derive(Clone, Copy, PartialEq)]
+pub struct Vector3b(mask32x4);
+impl Vector3b {
+    /// Creates a new vector from the given components
+    #[inline]
+    pub fn new(x: bool, y: bool, z: bool) -> Self {
+        Self(mask32x4::from_array([x, y, z, false]))
+    }
+
+    /// Creates a new vector from the given array
+    #[inline]
+    pub fn from_array(array: [bool; 3]) -> Self {
+        Self::new(array[0], array[1], array[2])
+    }
+
+    /// Converts the vector into an array
+    #[inline]
+    pub fn to_array(&self) -> [bool; 3] {
+        let array = self.0.to_array();
+        [array[0], array[1], array[2]]
+    }
+
+    #[inline]
+    fn from_simd_truncate(simd_mask: mask32x4) -> Self {
+        let mask = mask32x4::from_array([true, true, true, false]);
+        Self(simd_mask & mask)
+    }
+
+    #[inline]
+    fn simd_with_padding(&self, padding: bool) -> mask32x4 {
+        self.0 | mask32x4::from_array([false, false, false, padding])
+    }
+}
+
+/// A vector with 4 boolean components, produced by the component-wise comparisons of 4-component vectors
+#[derive(Clone, Copy, PartialEq)]
+pub struct Vector4b(mask32x4);
+impl Vector4b {
+    /// Creates a new vector from the given components
+    #[inline]
+    pub fn new(x: bool, y: bool, z: bool, w: bool) -> Self {
+        Self(mask32x4::from_array([x, y, z, w]))
+    }
+
+    /// Creates a new vector from the given array
+    #[inline]
+    pub fn from_array(array: [bool; 4]) -> Self {
+        Self(mask32x4::from_array(array))
+    }
+
+    /// Converts the vector into an array
+    #[inline]
+    pub fn to_array(&self) -> [bool; 4] {
+        self.0.to_array()
+    }
+
+    #[inline]
+    fn from_simd_truncate(simd_mask: mask32x4) -> Self {
+        Self(simd_mask)
+    }
+
+    #[inline]
+    fn simd_with_padding(&self, _padding: bool) -> mask32x4 {
+        self.0
+    }
+}
+
+macro_rules! impl_mask {
+    ($t:ty) => {
+        impl $t {
+            /// Checks whether all components are true
+            #[inline]
+            pub fn all(self) -> bool {
+                self.simd_with_padding(true).all()
+            }
+
+            /// Checks whether any component is true
+            #[inline]
+            pub fn any(self) -> bool {
+                self.simd_with_padding(false).any()
+            }
+        }
+
+        impl Debug for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt_components(f, stringify!($t), &self.to_array())
+            }
+        }
+        impl Display for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt_components(f, "", &self.to_array())
+            }
+        }
+
+        impl BitAnd for $t {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self(self.0 & rhs.0)
+            }
+        }
+        impl BitOr for $t {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(self.0 | rhs.0)
+            }
+        }
+        impl BitXor for $t {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+        impl Not for $t {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                Self::from_simd_truncate(!self.0)
+            }
+        }
+    };
+}
+
+impl_mask!(Vector2b);
+impl_mask!(Vector3b);
+impl_mask!(Vector4b);
+
+macro_rules! impl_compare {
+    ($t:ty, $tb:ty) => {
+        impl $t {
+            /// Compares each component of this vector with the corresponding component of rhs for equality
+            #[inline]
+            pub fn cmp_eq(self, rhs: Self) -> $tb {
+                <$tb>::from_simd_truncate(self.0.simd_eq(rhs.0))
+            }
+
+            /// Compares each component of this vector with the corresponding component of rhs for inequality
+            #[inline]
+            pub fn cmp_ne(self, rhs: Self) -> $tb {
+                <$tb>::from_simd_truncate(self.0.simd_ne(rhs.0))
+            }
+
+            /// Checks for each component whether this vector is less than rhs
+            #[inline]
+            pub fn cmp_lt(self, rhs: Self) -> $tb {
+                <$tb>::from_simd_truncate(self.0.simd_lt(rhs.0))
+            }
+
+            /// Checks for each component whether this vector is less than or equal to rhs
+            #[inline]
+            pub fn cmp_le(self, rhs: Self) -> $tb {
+                <$tb>::from_simd_truncate(self.0.simd_le(rhs.0))
+            }
+
+            /// Checks for each component whether this vector is greater than rhs
+            #[inline]
+            pub fn cmp_gt(self, rhs: Self) -> $tb {
+                <$tb>::from_simd_truncate(self.0.simd_gt(rhs.0))
+            }
+
+            /// Checks for each component whether this vector is greater than or equal to rhs
+            #[inline]
+            pub fn cmp_ge(self, rhs: Self) -> $tb {
+                <$tb>::from_simd_truncate(self.0.simd_ge(rhs.0))
+            }
+
+            /// Returns a vector with each component taken from if_true where mask is true and from if_false otherwise
+            #[inline]
+            pub fn select(mask: $tb, if_true: Self, if_false: Self) -> Self {
+                Self(mask.0.select(if_true.0, if_false.0))
+            }
+        }
+    };
+}
+
+impl_compare!(Vector2f, Vector2b);
+impl_compare!(Vector3f, Vector3b);
+impl_compare!(Vector4f, Vector4b);
+impl_compare!(Vector2i, Vector2b);
+impl_compare!(Vector3i, Vector3b);
+impl_compare!(Vector4i, Vector4b);
+impl_compare!(Vector2u, Vector2b);
+impl_compare!(Vector3u, Vector3b);
+impl_compare!(Vector4u, Vector4b);
+
+macro_rules! impl_iter_traits {
+    ($t:ty) => {
+        impl std::iter::Sum for $t {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+        impl<'a> std::iter::Sum<&'a $t> for $t {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+        impl std::iter::Product for $t {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, Mul::mul)
+            }
+        }
+        impl<'a> std::iter::Product<&'a $t> for $t {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+impl_iter_traits!(Vector2f);
+impl_iter_traits!(Vector3f);
+impl_iter_traits!(Vector4f);
+impl_iter_traits!(Vector2i);
+impl_iter_traits!(Vector3i);
+impl_iter_traits!(Vector4i);
+
+macro_rules! def_quat_field {
+    ($name:ident, $name_mut:ident, $i:literal, $t:ty) => {
+        #[doc = concat!("The ", stringify!($name), " component of the quaternion")]
+        #[inline]
+        pub const fn $name(&self) -> $t {
+            self.0.as_array()[$i]
+        }
+
+        #[doc = concat!("The ", stringify!($name), " component of the quaternion")]
+        #[inline]
+        pub fn $name_mut(&mut self) -> &mut $t {
+            self.0.index_mut($i)
+        }
+    };
+}
+
+/// A quaternion
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Quaternion(f32x4);
+impl Quaternion {
+    /// A quaternion representing no rotation
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    def_quat_field!(x, x_mut, 0, f32);
+    def_quat_field!(y, y_mut, 1, f32);
+    def_quat_field!(z, z_mut, 2, f32);
+    def_quat_field!(w, w_mut, 3, f32);
+
+    /// Creates a new quaternion from the given components
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self(f32x4::from_array([x, y, z, w]))
+    }
+
+    /// Creates a new quaternion from the given array
+    #[inline]
+    pub const fn from_array(array: [f32; 4]) -> Self {
+        Self(f32x4::from_array(array))
+    }
+
+    /// Converts the quaternion into an array
+    #[inline]
+    pub const fn to_array(&self) -> [f32; 4] {
+        self.0.to_array()
+    }
+
+    /// Returns an array reference to the quaternion
+    #[inline]
+    pub const fn as_array(&self) -> &[f32; 4] {
+        self.0.as_array()
+    }
+
+    /// Returns a mutable array reference to the quaternion
+    #[inline]
+    pub fn as_mut_array(&mut self) -> &mut [f32; 4] {
+        self.0.as_mut_array()
+    }
+
+    /// Creates a quaternion representing a rotation around an arbitrary axis
+    ///
+    /// The axis vector must be normalized, this is checked in debug builds
+    pub fn from_axis_angle(axis: Vector3f, angle: f32) -> Self {
+        debug_assert!(
+            (axis.len2() - 1.0).abs() < 1e-4,
+            "rotation axis {axis} is not normalized"
+        );
+
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self::new(axis.x() * sin, axis.y() * sin, axis.z() * sin, cos)
+    }
+
+    /// Creates a quaternion representing a rotation around an arbitrary axis, normalizing the axis first
+    #[inline]
+    pub fn from_axis_angle_normalized(axis: Vector3f, angle: f32) -> Self {
+        Self::from_axis_angle(axis.normalized(), angle)
+    }
+
+    /// Creates a quaternion representing a rotation around the X axis
+    pub fn from_angle_x(angle: f32) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self::new(sin, 0.0, 0.0, cos)
+    }
+
+    /// Creates a quaternion representing a rotation around the Y axis
+    pub fn from_angle_y(angle: f32) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self::new(0.0, sin, 0.0, cos)
+    }
+
+    /// Creates a quaternion representing a rotation around the Z axis
+    pub fn from_angle_z(angle: f32) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self::new(0.0, 0.0, sin, cos)
+    }
+
+    /// Creates a quaternion representing a rotation specified by yaw, pitch and roll angles
+    pub fn from_yaw_pitch_roll(yaw: f32, pitch: f32, roll: f32) -> Self {
+        let y = Self::from_angle_y(yaw);
+        let x = Self::from_angle_x(pitch);
+        let z = Self::from_angle_z(roll);
+        y * x * z
+    }
+
+    /// Creates a quaternion representing the shortest rotation that turns the direction of from into the direction of to
+    ///
+    /// If the vectors are opposite, the rotation happens around an arbitrary axis perpendicular to from,
+    /// use `rotation_between_with_axis` to control that axis
+    pub fn rotation_between(from: Vector3f, to: Vector3f) -> Self {
+        let fallback_axis = if from.x().abs() < (0.9 * from.len()) {
+            Vector3f::UNIT_X
+        } else {
+            Vector3f::UNIT_Y
+        };
+        Self::rotation_between_with_axis(from, to, fallback_axis)
+    }
+
+    /// Creates a quaternion representing the shortest rotation that turns the direction of from into the direction of to
+    ///
+    /// If the vectors are opposite, the rotation is a half turn around fallback_axis, made perpendicular to from first.
+    /// If fallback_axis is parallel to from, an arbitrary perpendicular axis is used instead.
+    pub fn rotation_between_with_axis(
+        from: Vector3f,
+        to: Vector3f,
+        fallback_axis: Vector3f,
+    ) -> Self {
+        const EPSILON: f32 = 1e-6;
+
+        let from = from.normalized();
+        let to = to.normalized();
+        let d = from.dot(to);
+
+        if d >= (1.0 - EPSILON) {
+            Self::IDENTITY
+        } else if d <= (-1.0 + EPSILON) {
+            let axis = fallback_axis - (from * from.dot(fallback_axis));
+            if axis.len2() < EPSILON {
+                Self::rotation_between(from, to)
+            } else {
+                Self::from_axis_angle(axis.normalized(), std::f32::consts::PI)
+            }
+        } else {
+            let axis = from.cross(to);
+            Self::new(axis.x(), axis.y(), axis.z(), 1.0 + d).normalized()
+        }
+    }
+
+    /// Creates a quaternion from the rotation part of a matrix
+    ///
+    /// The upper left 3x3 part of the matrix must be a pure rotation, this is the inverse of `Matrix4x4::rotation`
+    pub fn from_matrix(m: Matrix4x4) -> Self {
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::new(
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+                0.25 * s,
+            )
+        } else if (m[(0, 0)] > m[(1, 1)]) && (m[(0, 0)] > m[(2, 2)]) {
+            let s = (1.0 + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt() * 2.0;
+            Self::new(
+                0.25 * s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(2, 1)] - m[(1, 2)]) / s,
+            )
+        } else if m[(1, 1)] > m[(2, 2)] {
+            let s = (1.0 + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt() * 2.0;
+            Self::new(
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                0.25 * s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+            )
+        } else {
+            let s = (1.0 + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt() * 2.0;
+            Self::new(
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                0.25 * s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+            )
+        };
+
+        q.normalized()
+    }
+
+    /// Converts the quaternion into a rotation matrix, same as `Matrix4x4::rotation`
+    ///
+    /// The quaternion must be normalized
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix4x4 {
+        Matrix4x4::rotation(*self)
+    }
+
+    /// Converts the quaternion into an equivalent rotation around an axis
+    ///
+    /// The quaternion is normalized first and the returned angle is in the range [0, pi]
+    pub fn to_axis_angle(&self) -> (Vector3f, f32) {
+        let q = self.normalized();
+        let q = if q.w() < 0.0 { -q } else { q };
+
+        let w = q.w().clamp(-1.0, 1.0);
+        let angle = 2.0 * w.acos();
+
+        let s = (1.0 - (w * w)).sqrt();
+        if s < f32::EPSILON {
+            (Vector3f::new(1.0, 0.0, 0.0), angle)
+        } else {
+            let x = q.x() / s;
+            let y = q.y() / s;
+            let z = q.z() / s;
+
+            (Vector3f::new(x, y, z), angle)
+        }
+    }
+
+    /// Returns the angle of the rotation this quaternion represents, in the range [0, pi]
+    ///
+    /// The quaternion must be normalized, this is cheaper than `to_axis_angle` because the axis is not computed
+    #[inline]
+    pub fn angle(&self) -> f32 {
+        2.0 * self.w().abs().min(1.0).acos()
+    }
+
+    /// Returns the axis of the rotation this quaternion represents, matching the axis returned by `to_axis_angle`
+    ///
+    /// The X axis is returned if the quaternion represents no rotation
+    pub fn axis(&self) -> Vector3f {
+        let v = self.xyz();
+        let len = v.len();
+        if len < f32::EPSILON {
+            Vector3f::UNIT_X
+        } else if self.w() < 0.0 {
+            -v / len
+        } else {
+            v / len
+        }
+    }
+
+    /// Creates a quaternion from a rotation vector, whose direction is the rotation axis and whose length is the angle
+    ///
+    /// A zero vector results in the identity quaternion
+    pub fn from_scaled_axis(v: Vector3f) -> Self {
+        let angle = v.len();
+        if angle < f32::EPSILON {
+            Self::IDENTITY
+        } else {
+            Self::from_axis_angle(v / angle, angle)
+        }
+    }
+
+    /// Converts the quaternion into a rotation vector, whose direction is the rotation axis and whose length is the angle
+    ///
+    /// The angle is in the range [0, pi], the identity quaternion results in a zero vector
+    pub fn to_scaled_axis(&self) -> Vector3f {
+        let (axis, angle) = self.to_axis_angle();
+        axis * angle
+    }
+
+    /// Normalizes the quaternion
+    #[inline]
+    pub fn normalized(self) -> Self {
+        let len = self.xyzw().len();
+        if len == 0.0 {
+            self
+        } else {
+            self * (1.0 / len)
+        }
+    }
+
+    /// Normalizes the quaternion in place
+    #[inline]
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Checks whether this quaternion has a length of 1, up to a certain error in the squared length
+    #[inline]
+    pub fn is_normalized(&self, epsilon: f32) -> bool {
+        (self.xyzw().len2() - 1.0).abs() < epsilon
+    }
+
+    /// Checks whether this quaternion is equal to other, up to a certain error per component
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        let d = (self.0 - other.0).abs();
+        d.simd_le(f32x4::splat(epsilon)).all()
+    }
+
+    /// Checks whether this quaternion represents the same rotation as other, up to a certain error per component
+    ///
+    /// Unlike `abs_diff_eq` this treats q and -q as equal
+    #[inline]
+    pub fn abs_diff_eq_rotation(&self, other: &Self, epsilon: f32) -> bool {
+        self.abs_diff_eq(other, epsilon) || self.abs_diff_eq(&-*other, epsilon)
+    }
+
+    /// Returns the conjugate of this quaternion
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x(), -self.y(), -self.z(), self.w())
+    }
+
+    /// Returns the inverse of this quaternion
+    #[inline]
+    pub fn inverse(self) -> Self {
+        self.conjugate() * (1.0 / self.xyzw().len2())
+    }
+
+    /// Linearily interpolates between this quaternion and rhs
+    pub fn lerp(self, rhs: Self, t: f32) -> Self {
+        if self.xyzw().dot(rhs.xyzw()) < 0.0 {
+            self - ((rhs + self) * t)
+        } else {
+            self + ((rhs - self) * t)
+        }
+        .normalized()
+    }
+
+    /// Rotates a vector by this quaternion
+    ///
+    /// The quaternion must be normalized
+    #[inline]
+    pub fn rotate_vector(self, v: Vector3f) -> Vector3f {
+        v + Vector3f::cross(self.xyz(), Vector3f::cross(self.xyz(), v) + (v * self.w())) * 2.0
+    }
+
+    /// Rotates every vector in src by this quaternion, writing the results to dst
+    ///
+    /// The quaternion is converted into a rotation matrix once, so each vector only costs a matrix-vector product.
+    /// The quaternion must be normalized. Panics if src and dst have different lengths.
+    pub fn rotate_vectors(self, src: &[Vector3f], dst: &mut [Vector3f]) {
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "destination length must match the number of vectors"
+        );
+
+        let m = Matrix4x4::rotation(self);
+        let c0 = m.column(0);
+        let c1 = m.column(1);
+        let c2 = m.column(2);
+
+        for (v, out) in src.iter().zip(dst) {
+            let x = simd_swizzle_1!(v.0, 0);
+            let y = simd_swizzle_1!(v.0, 1);
+            let z = simd_swizzle_1!(v.0, 2);
+
+            // the W lane of the rotation columns is zero, so the padding stays zero
+            *out = Vector3f((c0 * x) + (c1 * y) + (c2 * z));
+        }
+    }
+
+    /// Rotates a vector by this quaternion, normalizing the quaternion first
+    #[inline]
+    pub fn rotate_vector_unnormalized(self, v: Vector3f) -> Vector3f {
+        self.normalized().rotate_vector(v)
+    }
+
+    /// Spherically interpolates between this quaternion and rhs
+    ///
+    /// rhs is negated if necessary to interpolate along the shorter arc. If the inputs are nearly
+    /// parallel after that (including q and -q), this falls back to linear interpolation
+    /// instead of dividing by a vanishing sine.
+    ///
+    /// The inputs are normalized first, so quaternions that drifted away from unit length still interpolate correctly.
+    pub fn slerp(self, rhs: Self, t: f32) -> Self {
+        let lhs = self.normalized();
+        let rhs = rhs.normalized();
+
+        let temp: Self;
+        let mut cosom = lhs.xyzw().dot(rhs.xyzw()).min(1.0);
+
+        if cosom < 0.0 {
+            temp = -rhs;
+            cosom = -cosom;
+        } else {
+            temp = rhs;
+        }
+
+        let scale1: f32;
+        let scale2: f32;
+        if (1.0 - cosom) > f32::EPSILON {
+            let omega = cosom.acos();
+            let sinom = 1.0 / omega.sin();
+            scale1 = ((1.0 - t) * omega).sin() * sinom;
+            scale2 = (t * omega).sin() * sinom;
+        } else {
+            scale1 = 1.0 - t;
+            scale2 = t;
+        }
+
+        ((lhs * scale1) + (temp * scale2)).normalized()
+    }
+
+    /// Linearily interpolates between this quaternion and rhs and normalizes the result
+    ///
+    /// Like `slerp` this takes the shorter arc, but the angular velocity is not constant
+    pub fn nlerp(self, rhs: Self, t: f32) -> Self {
+        let rhs = if self.xyzw().dot(rhs.xyzw()) < 0.0 {
+            -rhs
+        } else {
+            rhs
+        };
+
+        ((self * (1.0 - t)) + (rhs * t)).normalized()
+    }
+
+    /// Calculates the weighted average of multiple rotations
+    ///
+    /// Every quaternion is flipped into the hemisphere of the first one before accumulating, and the sum is normalized.
+    /// An empty slice or weights summing up to a zero quaternion result in the identity quaternion.
+    /// Panics if quats and weights have different lengths.
+    pub fn weighted_average(quats: &[Quaternion], weights: &[f32]) -> Self {
+        assert_eq!(
+            quats.len(),
+            weights.len(),
+            "number of weights must match the number of quaternions"
+        );
+
+        let Some(first) = quats.first() else {
+            return Self::IDENTITY;
+        };
+
+        let mut sum = Self(f32x4::splat(0.0));
+        for (&q, &weight) in quats.iter().zip(weights) {
+            let q = if first.xyzw().dot(q.xyzw()) < 0.0 {
+                -q
+            } else {
+                q
+            };
+            sum += q * weight;
+        }
+
+        if sum.xyzw().len2() == 0.0 {
+            Self::IDENTITY
+        } else {
+            sum.normalized()
+        }
+    }
+}
+impl Debug for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_components(f, "Quaternion", self.as_array())
+    }
+}
+impl Display for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_components(f, "", self.as_array())
+    }
+}
+impl Index<usize> for Quaternion {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+impl IndexMut<usize> for Quaternion {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+impl Add for Quaternion {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+impl AddAssign for Quaternion {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl Sub for Quaternion {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+impl SubAssign for Quaternion {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+impl Mul<f32> for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self(self.0 * f32x4::splat(rhs))
+    }
+}
+impl MulAssign<f32> for Quaternion {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+impl Div<f32> for Quaternion {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Self(self.0 / f32x4::splat(rhs))
+    }
+}
+impl DivAssign<f32> for Quaternion {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let xyz = (rhs.xyz() * self.w())
+            + (self.xyz() * rhs.w())
+            + Vector3f::cross(self.xyz(), rhs.xyz());
+        let w = (self.w() * rhs.w()) - Vector3f::dot(self.xyz(), rhs.xyz());
+        Self::new(xyz.x(), xyz.y(), xyz.z(), w)
+    }
+}
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl Mul<Vector3f> for Quaternion {
+    type Output = Vector3f;
+
+    #[inline]
+    fn mul(self, rhs: Vector3f) -> Self::Output {
+        self.rotate_vector(rhs)
+    }
+}
+
+impl From<Matrix4x4> for Quaternion {
+    #[inline]
+    fn from(value: Matrix4x4) -> Self {
+        Self::from_matrix(value)
+    }
+}
+
+impl From<(Vector3f, f32)> for Quaternion {
+    /// Converts an axis and an angle into a quaternion, the axis must be normalized
+    #[inline]
+    fn from((axis, angle): (Vector3f, f32)) -> Self {
+        Self::from_axis_angle(axis, angle)
+    }
+}
+
+/// The error returned when converting a slice with the wrong number of elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryFromSliceError {
+    /// The slice did not contain exactly the expected number of elements
+    LengthMismatch {
+        /// The number of elements required for the conversion
+        expected: usize,
+        /// The number of elements in the slice
+        actual: usize,
+    },
+}
+impl Display for TryFromSliceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch { expected, actual } => write!(
+                f,
+                "expected a slice of {} elements, got {} elements",
+                expected, actual
+            ),
+        }
+    }
+}
+impl std::error::Error for TryFromSliceError {}
+
+/// The error returned when collecting an iterator with the wrong number of elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthError {
+    /// The iterator ended before yielding the expected number of elements
+    TooFew {
+        /// The number of elements required for the conversion
+        expected: usize,
+        /// The number of elements the iterator yielded
+        actual: usize,
+    },
+    /// The iterator yielded more than the expected number of elements
+    TooMany {
+        /// The number of elements required for the conversion
+        expected: usize,
+    },
+}
+impl Display for LengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooFew { expected, actual } => write!(
+                f,
+                "expected {} elements, the iterator ended after {} elements",
+                expected, actual
+            ),
+            Self::TooMany { expected } => {
+                write!(
+                    f,
+                    "expected {} elements, the iterator yielded more",
+                    expected
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for LengthError {}
+
+/// The error returned when the parameters of a projection matrix are invalid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionError {
+    /// The field of view was not greater than zero
+    NonPositiveFov,
+    /// The aspect ratio was not greater than zero
+    NonPositiveAspectRatio,
+    /// The viewport width or height was not greater than zero
+    NonPositiveSize,
+    /// The near plane was not greater than one
+    NearPlaneTooClose,
+    /// The far plane was not further away than the near plane
+    FarPlaneNotBeyondNear,
+}
+impl Display for ProjectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonPositiveFov => write!(f, "field of view must be greater than zero"),
+            Self::NonPositiveAspectRatio => write!(f, "aspect ratio must be greater than zero"),
+            Self::NonPositiveSize => {
+                write!(f, "viewport width and height must be greater than zero")
+            }
+            Self::NearPlaneTooClose => write!(f, "near plane must be greater than one"),
+            Self::FarPlaneNotBeyondNear => {
+                write!(f, "far plane must be greater than the near plane")
+            }
+        }
+    }
+}
+impl std::error::Error for ProjectionError {}
+
+#[inline]
+fn slice_to_array<T: Copy, const N: usize>(slice: &[T]) -> Result<[T; N], TryFromSliceError> {
+    slice
+        .try_into()
+        .map_err(|_| TryFromSliceError::LengthMismatch {
+            expected: N,
+            actual: slice.len(),
+        })
+}
+
+#[inline]
+fn iter_to_array<T: Copy + Default, const N: usize>(
+    iter: impl IntoIterator<Item = T>,
+) -> Result<[T; N], LengthError> {
+    let mut iter = iter.into_iter();
+    let mut array = [T::default(); N];
+    for (i, elem) in array.iter_mut().enumerate() {
+        *elem = iter.next().ok_or(LengthError::TooFew {
+            expected: N,
+            actual: i,
+        })?;
+    }
+
+    match iter.next() {
+        Some(_) => Err(LengthError::TooMany { expected: N }),
+        None => Ok(array),
+    }
+}
+
+macro_rules! impl_to_array {
+    ($t:ty, $ts:ty, $n:literal) => {
+        impl $t {
+            /// Creates a new value from the elements of an iterator
+            ///
+            /// Returns an error if the iterator does not yield exactly as many elements as there are components,
+            /// at most one element past that is consumed.
+            #[inline]
+            pub fn try_from_iter(iter: impl IntoIterator<Item = $ts>) -> Result<Self, LengthError> {
+                iter_to_array(iter).map(Self::from_array)
+            }
+        }
+
+        impl From<[$ts; $n]> for $t {
+            fn from(a: [$ts; $n]) -> Self {
+                Self::from_array(a)
+            }
+        }
+
+        impl TryFrom<&[$ts]> for $t {
+            type Error = TryFromSliceError;
+
+            fn try_from(slice: &[$ts]) -> Result<Self, Self::Error> {
+                slice_to_array(slice).map(Self::from_array)
+            }
+        }
+
+        impl Into<[$ts; $n]> for $t {
+            fn into(self) -> [$ts; $n] {
+                self.to_array()
+            }
+        }
+
+        impl AsRef<[$ts; $n]> for $t {
+            fn as_ref(&self) -> &[$ts; $n] {
+                self.as_array()
+            }
+        }
+
+        impl AsMut<[$ts; $n]> for $t {
+            fn as_mut(&mut self) -> &mut [$ts; $n] {
+                self.as_mut_array()
+            }
+        }
+
+        impl std::borrow::Borrow<[$ts; $n]> for $t {
+            fn borrow(&self) -> &[$ts; $n] {
+                self.as_array()
+            }
+        }
+
+        impl std::borrow::BorrowMut<[$ts; $n]> for $t {
+            fn borrow_mut(&mut self) -> &mut [$ts; $n] {
+                self.as_mut_array()
+            }
+        }
+    };
+}
+
+impl_to_array!(Vector2f, f32, 2);
+impl_to_array!(Vector3f, f32, 3);
+impl_to_array!(Vector4f, f32, 4);
+impl_to_array!(Vector2i, i32, 2);
+impl_to_array!(Vector3i, i32, 3);
+impl_to_array!(Vector4i, i32, 4);
+impl_to_array!(Vector2u, u32, 2);
+impl_to_array!(Vector3u, u32, 3);
+impl_to_array!(Vector4u, u32, 4);
+impl_to_array!(Quaternion, f32, 4);
+
+macro_rules! impl_from_scalar {
+    ($t:ty, $ts:ty) => {
+        impl From<$ts> for $t {
+            /// Creates a vector with all components set to the given scalar, same as `from_scalar`
+            #[inline]
+            fn from(scalar: $ts) -> Self {
+                Self::from_scalar(scalar)
+            }
+        }
+    };
+}
+
+impl_from_scalar!(Vector2f, f32);
+impl_from_scalar!(Vector3f, f32);
+impl_from_scalar!(Vector4f, f32);
+impl_from_scalar!(Vector2i, i32);
+impl_from_scalar!(Vector3i, i32);
+impl_from_scalar!(Vector4i, i32);
+
+macro_rules! impl_shuffle {
+    ($t:ty, $n:literal) => {
+        impl $t {
+            /// Returns a vector with each component selected from this vector by the corresponding runtime index
+            ///
+            /// Panics if any index is out of range
+            #[inline]
+            pub fn shuffle(self, indices: [usize; $n]) -> Self {
+                let array = self.as_array();
+                Self::from_array(indices.map(|i| array[i]))
+            }
+        }
+    };
+}
+
+impl_shuffle!(Vector2f, 2);
+impl_shuffle!(Vector3f, 3);
+impl_shuffle!(Vector4f, 4);
+impl_shuffle!(Vector2i, 2);
+impl_shuffle!(Vector3i, 3);
+impl_shuffle!(Vector4i, 4);
+impl_shuffle!(Vector2u, 2);
+impl_shuffle!(Vector3u, 3);
+impl_shuffle!(Vector4u, 4);
+
+macro_rules! impl_float_bits {
+    ($t:ty, $n:literal) => {
+        impl $t {
+            /// Returns the raw bit patterns of the components
+            #[inline]
+            pub fn to_bits(self) -> [u32; $n] {
+                self.to_array().map(f32::to_bits)
+            }
+
+            /// Creates a new value from the raw bit patterns of the components
+            #[inline]
+            pub fn from_bits(bits: [u32; $n]) -> Self {
+                Self::from_array(bits.map(f32::from_bits))
+            }
+        }
+    };
+}
+
+impl_float_bits!(Vector2f, 2);
+impl_float_bits!(Vector3f, 3);
+impl_float_bits!(Vector4f, 4);
+impl_float_bits!(Quaternion, 4);
+
+macro_rules! impl_dim_conversions {
+    ($t2:ident, $t3:ident, $t4:ident, $from_v2:ident, $from_v3:ident, $zero:literal) => {
+        impl From<$t2> for $t3 {
+            fn from(v: $t2) -> Self {
+                Self::$from_v2(v, $zero)
+            }
+        }
+
+        impl From<$t2> for $t4 {
+            fn from(v: $t2) -> Self {
+                Self::$from_v2(v, $zero, $zero)
+            }
+        }
+
+        impl From<$t3> for $t4 {
+            fn from(v: $t3) -> Self {
+                Self::$from_v3(v, $zero)
+            }
+        }
+
+        impl From<$t3> for $t2 {
+            fn from(v: $t3) -> Self {
+                v.xy()
+            }
+        }
+
+        impl From<$t4> for $t2 {
+            fn from(v: $t4) -> Self {
+                v.xy()
+            }
+        }
+
+        impl From<$t4> for $t3 {
+            /// Drops the w component, no perspective divide is performed
+            fn from(v: $t4) -> Self {
+                v.xyz()
+            }
+        }
+    };
+}
+
+impl_dim_conversions!(Vector2f, Vector3f, Vector4f, from_v2f, from_v3f, 0.0);
+impl_dim_conversions!(Vector2i, Vector3i, Vector4i, from_v2i, from_v3i, 0);
+
+macro_rules! format_width {
+    ($value:expr, $precision:expr) => {{
+        let s = match $precision {
+            Some(precision) => format!("{:+.*}", precision, $value),
+            None => format!("{:+}", $value),
+        };
+        let w = s.chars().count();
+        (s, w)
+    }};
+}
+
+/// Column-major 2x3 matrix, indexed as [row, column]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C, align(8))]
+pub struct Matrix2x3([f32x2; 3]);
+impl Matrix2x3 {
+    /// A matrix representing no transformation
+    pub const IDENTITY: Self = Self([
+        f32x2::from_array([1.0, 0.0]),
+        f32x2::from_array([0.0, 1.0]),
+        f32x2::from_array([0.0, 0.0]),
+    ]);
+
+    /// Creates a new matrix from individual elements
+    #[rustfmt::skip]
+    pub const fn new(
+        e00: f32, e10: f32, // Column 0
+        e01: f32, e11: f32, // Column 1
+        e02: f32, e12: f32, // Column 2
+    ) -> Self {
+        Self([
+            f32x2::from_array([e00, e10]),
+            f32x2::from_array([e01, e11]),
+            f32x2::from_array([e02, e12]),
+        ])
+    }
+
+    /// Creates a new matrix from the given array
+    #[inline]
+    pub const fn from_array(array: [[f32; 2]; 3]) -> Self {
+        Self([
+            f32x2::from_array(array[0]),
+            f32x2::from_array(array[1]),
+            f32x2::from_array(array[2]),
+        ])
+    }
+
+    /// Converts the matrix into an array
+    #[inline]
+    pub const fn to_array(&self) -> [[f32; 2]; 3] {
+        [
+            self.0[0].to_array(),
+            self.0[1].to_array(),
+            self.0[2].to_array(),
+        ]
+    }
+
+    /// Converts the matrix into an array of its two rows, each padded to 4 elements
+    ///
+    /// The layout is `[[m00, m01, m02, 0.0], [m10, m11, m12, 0.0]]`, which can be uploaded to a uniform buffer as two
+    /// 16 byte aligned `vec4`s. A shader transforms a point p with `vec2(dot(row0.xyz, vec3(p, 1.0)), dot(row1.xyz, vec3(p, 1.0)))`.
+    #[inline]
+    pub const fn to_padded_array(&self) -> [[f32; 4]; 2] {
+        let [c0, c1, c2] = self.to_array();
+        [[c0[0], c1[0], c2[0], 0.0], [c0[1], c1[1], c2[1], 0.0]]
+    }
+
+    #[inline]
+    const fn column(&self, index: usize) -> f32x2 {
+        self.0[index]
+    }
+
+    /// Checks whether this matrix is the identity matrix, up to a certain error
+    pub fn is_identity(&self, epsilon: f32) -> bool {
+        const I0: f32x2 = f32x2::from_array([1.0, 0.0]);
+        const I1: f32x2 = f32x2::from_array([0.0, 1.0]);
+        const I2: f32x2 = f32x2::from_array([0.0, 0.0]);
+
+        let epsilon = f32x2::splat(epsilon);
+
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+
+        let d0 = (c0 - I0).abs();
+        let d1 = (c1 - I1).abs();
+        let d2 = (c2 - I2).abs();
+
+        let lt0 = d0.simd_lt(epsilon).all();
+        let lt1 = d1.simd_lt(epsilon).all();
+        let lt2 = d2.simd_lt(epsilon).all();
+
+        lt0 && lt1 && lt2
+    }
+
+    /// Checks whether this matrix is equal to other, up to a certain error per element
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        let epsilon = f32x2::splat(epsilon);
+
+        let d0 = (self.column(0) - other.column(0)).abs();
+        let d1 = (self.column(1) - other.column(1)).abs();
+        let d2 = (self.column(2) - other.column(2)).abs();
+
+        let le0 = d0.simd_le(epsilon).all();
+        let le1 = d1.simd_le(epsilon).all();
+        let le2 = d2.simd_le(epsilon).all();
+
+        le0 && le1 && le2
+    }
+
+    /// Returns a matrix containing the absolute value of each element
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self([
+            self.column(0).abs(),
+            self.column(1).abs(),
+            self.column(2).abs(),
+        ])
+    }
+
+    /// Returns the largest absolute value of any element
+    ///
+    /// If any element is NaN the result is NaN.
+    pub fn max_abs_element(&self) -> f32 {
+        let d0 = self.column(0).abs();
+        let d1 = self.column(1).abs();
+        let d2 = self.column(2).abs();
+
+        if (d0.is_nan() | d1.is_nan() | d2.is_nan()).any() {
+            return f32::NAN;
+        }
+
+        d0.simd_max(d1).simd_max(d2).reduce_max()
+    }
+
+    /// Creates a matrix representing a translation along the X axis
+    pub fn translation_x(translation: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 2)] = translation;
+        m
+    }
+
+    /// Creates a matrix representing a translation along the Y axis
+    pub fn translation_y(translation: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(1, 2)] = translation;
+        m
+    }
+
+    /// Creates a matrix representing a translation
+    pub fn translation(translation: Vector2f) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 2)] = translation.x();
+        m[(1, 2)] = translation.y();
+        m
+    }
+
+    /// Creates a matrix representing a scaling along the X axis
+    pub fn scaling_x(scale: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 0)] = scale;
+        m
+    }
+
+    /// Creates a matrix representing a scaling along the Y axis
+    pub fn scaling_y(scale: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(1, 1)] = scale;
+        m
+    }
+
+    /// Creates a matrix representing a scaling
+    pub fn scaling(scale: Vector2f) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 0)] = scale.x();
+        m[(1, 1)] = scale.y();
+        m
+    }
+
+    /// Creates a matrix representing a rotation
+    pub fn rotation(angle: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        let (sin, cos) = angle.sin_cos();
+        m[(0, 0)] = cos;
+        m[(0, 1)] = -sin;
+        m[(1, 0)] = sin;
+        m[(1, 1)] = cos;
+        m
+    }
+
+    /// Creates a matrix representing a rotation around the given pivot point
+    pub fn rotation_around(angle: f32, pivot: Vector2f) -> Self {
+        let mut m = Self::rotation(angle);
+        let translation = pivot - m.mul_no_translate(pivot);
+        m[(0, 2)] = translation.x();
+        m[(1, 2)] = translation.y();
+        m
+    }
+
+    /// Creates a matrix representing a transformation specified by rotation and translation, applied in that order
+    pub fn from_angle_translation(angle: f32, translation: Vector2f) -> Self {
+        let mut m = Self::rotation(angle);
+        m[(0, 2)] = translation.x();
+        m[(1, 2)] = translation.y();
+        m
+    }
+
+    /// Creates a matrix representing a transformation specified by scale, rotation and translation, applied in that order
+    pub fn from_scale_rotation_translation(
+        scale: Vector2f,
+        rotation: f32,
+        translation: Vector2f,
+    ) -> Self {
+        let scaling = Self::scaling(scale);
+        let rotation = Self::rotation(rotation);
+        let translation = Self::translation(translation);
+        translation * rotation * scaling
+    }
+
+    /// Calculates the determinant of this matrix
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        let c0 = Vector2f(self.column(0));
+        let c1 = Vector2f(self.column(1));
+        Vector2f::cross(c0, c1)
+    }
+
+    /// Calculates the inverse of this matrix
+    pub fn inverse(&self) -> Self {
+        let det = self.determinant();
+        let inv_det = 1.0 / det;
+
+        let _e00 = self[(0, 0)];
+        let _e10 = self[(1, 0)];
+        let _e01 = self[(0, 1)];
+        let _e11 = self[(1, 1)];
+        let _e02 = self[(0, 2)];
+        let _e12 = self[(1, 2)];
+
+        let e00 = _e11 * inv_det;
+        let e10 = -_e01 * inv_det;
+        let e01 = -_e10 * inv_det;
+        let e11 = _e00 * inv_det;
+        let e02 = (_e01 * _e12 - _e02 * _e11) * inv_det;
+        let e12 = (_e02 * _e10 - _e00 * _e12) * inv_det;
+
+        Self::new(e00, e10, e01, e11, e02, e12)
+    }
+
+    /// Linearily interpolates between this matrix and rhs
+    pub fn lerp(lhs: &Self, rhs: &Self, t: f32) -> Self {
+        let lhs_c0 = lhs.column(0);
+        let lhs_c1 = lhs.column(1);
+        let lhs_c2 = lhs.column(2);
+
+        let rhs_c0 = rhs.column(0);
+        let rhs_c1 = rhs.column(1);
+        let rhs_c2 = rhs.column(2);
+
+        let t = f32x2::splat(t);
+        let c0 = lhs_c0 + ((rhs_c0 - lhs_c0) * t);
+        let c1 = lhs_c1 + ((rhs_c1 - lhs_c1) * t);
+        let c2 = lhs_c2 + ((rhs_c2 - lhs_c2) * t);
+
+        Self([c0, c1, c2])
+    }
+
+    /// Multiples the matrix with a vector while not applying translation
+    pub fn mul_no_translate(&self, rhs: Vector2f) -> Vector2f {
+        let r0 = self.column(0);
+        let r1 = self.column(1);
+
+        let x = simd_swizzle!(rhs.0, [0, 0]);
+        let y = simd_swizzle!(rhs.0, [1, 1]);
+        Vector2f((r0 * x) + (r1 * y))
+    }
+
+    /// Transforms a point, applying translation
+    ///
+    /// Equivalent to `self * point`
+    #[inline]
+    pub fn transform_point(&self, point: Vector2f) -> Vector2f {
+        *self * point
+    }
+
+    /// Transforms a direction vector, not applying translation
+    ///
+    /// Equivalent to `self.mul_no_translate(vector)`
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector2f) -> Vector2f {
+        self.mul_no_translate(vector)
+    }
+
+    /// Converts the matrix into a 4x4 matrix
+    #[rustfmt::skip]
+    pub fn to_matrix4x4(&self) -> Matrix4x4 {
+        let e00 = self[(0, 0)];
+        let e10 = self[(1, 0)];
+        let e01 = self[(0, 1)];
+        let e11 = self[(1, 1)];
+        let e02 = self[(0, 2)];
+        let e12 = self[(1, 2)];
+
+        Matrix4x4::from_array([
+            [e00, e10, 0.0, 0.0],
+            [e01, e11, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [e02, e12, 0.0, 1.0],
+        ])
+    }
+
+    #[rustfmt::skip]
+    fn format_elements(&self, precision: Option<usize>) -> ([[String; 2]; 3], usize) {
+        let (s00, w00) = format_width!(self[(0, 0)], precision);
+        let (s10, w10) = format_width!(self[(1, 0)], precision);
+
+        let (s01, w01) = format_width!(self[(0, 1)], precision);
+        let (s11, w11) = format_width!(self[(1, 1)], precision);
+
+        let (s02, w02) = format_width!(self[(0, 2)], precision);
+        let (s12, w12) = format_width!(self[(1, 2)], precision);
+
+        let strings = [
+            [s00, s10],
+            [s01, s11],
+            [s02, s12],
+        ];
+
+        let widths = [
+            w00, w10,
+            w01, w11,
+            w02, w12,
+        ];
+
+        (strings, widths.into_iter().max().unwrap())
+    }
+}
+impl Index<(usize, usize)> for Matrix2x3 {
+    type Output = f32;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.0[index.1][index.0]
+    }
+}
+impl IndexMut<(usize, usize)> for Matrix2x3 {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.0[index.1][index.0]
+    }
+}
+impl Mul<Vector2f> for Matrix2x3 {
+    type Output = Vector2f;
+
+    fn mul(self, rhs: Vector2f) -> Self::Output {
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+
+        let x = simd_swizzle!(rhs.0, [0, 0]);
+        let y = simd_swizzle!(rhs.0, [1, 1]);
+        Vector2f((c0 * x) + (c1 * y) + c2)
+    }
+}
+impl Mul for Matrix2x3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let lhs_c0 = self.column(0);
+        let lhs_c1 = self.column(1);
+        let lhs_c2 = self.column(2);
+
+        let c0 = { (lhs_c0 * f32x2::splat(rhs[(0, 0)])) + (lhs_c1 * f32x2::splat(rhs[(1, 0)])) };
+        let c1 = { (lhs_c0 * f32x2::splat(rhs[(0, 1)])) + (lhs_c1 * f32x2::splat(rhs[(1, 1)])) };
+        let c2 = {
+            (lhs_c0 * f32x2::splat(rhs[(0, 2)])) + (lhs_c1 * f32x2::splat(rhs[(1, 2)])) + lhs_c2
+        };
+
+        Self([c0, c1, c2])
+    }
+}
+impl TryFrom<&[f32]> for Matrix2x3 {
+    type Error = TryFromSliceError;
+
+    /// Creates a matrix from 6 elements in column-major order
+    fn try_from(slice: &[f32]) -> Result<Self, Self::Error> {
+        let a: [f32; 6] = slice_to_array(slice)?;
+        Ok(Self::from_array([[a[0], a[1]], [a[2], a[3]], [a[4], a[5]]]))
+    }
+}
+impl Debug for Matrix2x3 {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (strings, width) = self.format_elements(f.precision());
+        let s = format!("Matrix2x3(\
+            \n\t{:<width$}, {:<width$}, {:<width$},\
+            \n\t{:<width$}, {:<width$}, {:<width$},\
+            \n)",
+            strings[0][0], strings[1][0], strings[2][0],
+            strings[0][1], strings[1][1], strings[2][1],
+            width = width
+        );
+
+        let s = s.replace('+', " ");
+        write!(f, "{}", s)
+    }
+}
+impl Display for Matrix2x3 {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (strings, width) = self.format_elements(f.precision());
+        let (zero, one) = match f.precision() {
+            Some(precision) => (format!("{:.*}", precision, 0.0), format!("{:.*}", precision, 1.0)),
+            None => (0.0.to_string(), 1.0.to_string()),
+        };
+        let s = format!("\
+            |{:<width$}   {:<width$}   {:<width$}|\n\
+            |{:<width$}   {:<width$}   {:<width$}|\n\
+            |{:<width$}   {:<width$}   {:<width$}|",
+            strings[0][0], strings[1][0], strings[2][0],
+            strings[0][1], strings[1][1], strings[2][1],
+            zero         , zero         , one          ,
+            width = width
+        );
+
+        let s = s.replace('+', " ");
+        write!(f, "{}", s)
+    }
+}
+
+/// Column-major 4x4 matrix, indexed as [row, column]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Matrix4x4([f32x4; 4]);
+impl Matrix4x4 {
+    /// A matrix representing no transformation
+    pub const IDENTITY: Self = Self([
+        f32x4::from_array([1.0, 0.0, 0.0, 0.0]),
+        f32x4::from_array([0.0, 1.0, 0.0, 0.0]),
+        f32x4::from_array([0.0, 0.0, 1.0, 0.0]),
+        f32x4::from_array([0.0, 0.0, 0.0, 1.0]),
+    ]);
+
+    /// Creates a new matrix from individual elements
+    #[rustfmt::skip]
+    pub const fn new(
+        e00: f32, e10: f32, e20: f32, e30: f32, // Column 0
+        e01: f32, e11: f32, e21: f32, e31: f32, // Column 1
+        e02: f32, e12: f32, e22: f32, e32: f32, // Column 2
+        e03: f32, e13: f32, e23: f32, e33: f32, // Column 3
+    ) -> Self {
+        Self([
+            f32x4::from_array([e00, e10, e20, e30]),
+            f32x4::from_array([e01, e11, e21, e31]),
+            f32x4::from_array([e02, e12, e22, e32]),
+            f32x4::from_array([e03, e13, e23, e33]),
+        ])
+    }
+
+    /// Creates a new matrix from the given array
+    #[inline]
+    pub const fn from_array(array: [[f32; 4]; 4]) -> Self {
+        Self([
+            f32x4::from_array(array[0]),
+            f32x4::from_array(array[1]),
+            f32x4::from_array(array[2]),
+            f32x4::from_array(array[3]),
+        ])
+    }
+
+    /// Converts the matrix into an array
+    #[inline]
+    pub const fn to_array(&self) -> [[f32; 4]; 4] {
+        [
+            self.0[0].to_array(),
+            self.0[1].to_array(),
+            self.0[2].to_array(),
+            self.0[3].to_array(),
+        ]
+    }
+
+    #[inline]
+    const fn column(&self, index: usize) -> f32x4 {
+        self.0[index]
+    }
+
+    /// Returns the column at the given index
+    #[inline]
+    pub const fn col(&self, index: usize) -> Vector4f {
+        Vector4f(self.column(index))
+    }
+
+    /// Returns the row at the given index
+    #[inline]
+    pub fn row(&self, index: usize) -> Vector4f {
+        Vector4f::new(
+            self[(index, 0)],
+            self[(index, 1)],
+            self[(index, 2)],
+            self[(index, 3)],
+        )
+    }
+
+    /// Replaces the column at the given index
+    #[inline]
+    pub fn set_column(&mut self, index: usize, col: Vector4f) {
+        self.0[index] = col.0;
+    }
+
+    /// Replaces the row at the given index
+    #[inline]
+    pub fn set_row(&mut self, index: usize, row: Vector4f) {
+        self[(index, 0)] = row.x();
+        self[(index, 1)] = row.y();
+        self[(index, 2)] = row.z();
+        self[(index, 3)] = row.w();
+    }
 
     /// Checks whether this matrix is the identity matrix, up to a certain error
     pub fn is_identity(&self, epsilon: f32) -> bool {
-        const I0: f32x2 = f32x2::from_array([1.0, 0.0]);
-        const I1: f32x2 = f32x2::from_array([0.0, 1.0]);
-        const I2: f32x2 = f32x2::from_array([0.0, 0.0]);
+        const I0: f32x4 = f32x4::from_array([1.0, 0.0, 0.0, 0.0]);
+        const I1: f32x4 = f32x4::from_array([0.0, 1.0, 0.0, 0.0]);
+        const I2: f32x4 = f32x4::from_array([0.0, 0.0, 1.0, 0.0]);
+        const I3: f32x4 = f32x4::from_array([0.0, 0.0, 0.0, 1.0]);
+
+        let epsilon = f32x4::splat(epsilon);
+
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+        let c3 = self.column(3);
+
+        let d0 = (c0 - I0).abs();
+        let d1 = (c1 - I1).abs();
+        let d2 = (c2 - I2).abs();
+        let d3 = (c3 - I3).abs();
+
+        let lt0 = d0.simd_lt(epsilon).all();
+        let lt1 = d1.simd_lt(epsilon).all();
+        let lt2 = d2.simd_lt(epsilon).all();
+        let lt3 = d3.simd_lt(epsilon).all();
+
+        lt0 && lt1 && lt2 && lt3
+    }
+
+    /// Checks whether the bottom row of this matrix is (0, 0, 0, 1), up to a certain error
+    ///
+    /// Only affine matrices can be inverted using `transform_inverse`
+    pub fn is_affine(&self, epsilon: f32) -> bool {
+        const I: f32x4 = f32x4::from_array([0.0, 0.0, 0.0, 1.0]);
+
+        let epsilon = f32x4::splat(epsilon);
+
+        let r3 = f32x4::from_array([self[(3, 0)], self[(3, 1)], self[(3, 2)], self[(3, 3)]]);
+        let d3 = (r3 - I).abs();
+
+        d3.simd_lt(epsilon).all()
+    }
+
+    /// Checks whether the columns of the upper-left 3x3 part of this matrix are mutually orthonormal, up to a certain error
+    pub fn is_orthogonal(&self, epsilon: f32) -> bool {
+        const I: f32x4 = f32x4::from_array([1.0, 1.0, 1.0, 0.0]);
+
+        let epsilon = f32x4::splat(epsilon);
+
+        let x = Vector3f::from_simd_truncate(self.column(0));
+        let y = Vector3f::from_simd_truncate(self.column(1));
+        let z = Vector3f::from_simd_truncate(self.column(2));
+
+        let len2 = f32x4::from_array([x.len2(), y.len2(), z.len2(), 0.0]);
+        let dots = f32x4::from_array([x.dot(y), y.dot(z), z.dot(x), 0.0]);
+
+        let d0 = (len2 - I).abs();
+        let d1 = dots.abs();
+
+        let lt0 = d0.simd_lt(epsilon).all();
+        let lt1 = d1.simd_lt(epsilon).all();
+
+        lt0 && lt1
+    }
+
+    /// Checks whether this matrix is equal to other, up to a certain error per element
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        let epsilon = f32x4::splat(epsilon);
+
+        let d0 = (self.column(0) - other.column(0)).abs();
+        let d1 = (self.column(1) - other.column(1)).abs();
+        let d2 = (self.column(2) - other.column(2)).abs();
+        let d3 = (self.column(3) - other.column(3)).abs();
+
+        let le0 = d0.simd_le(epsilon).all();
+        let le1 = d1.simd_le(epsilon).all();
+        let le2 = d2.simd_le(epsilon).all();
+        let le3 = d3.simd_le(epsilon).all();
+
+        le0 && le1 && le2 && le3
+    }
+
+    /// Returns a matrix containing the absolute value of each element
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self([
+            self.column(0).abs(),
+            self.column(1).abs(),
+            self.column(2).abs(),
+            self.column(3).abs(),
+        ])
+    }
+
+    /// Returns the largest absolute value of any element
+    ///
+    /// If any element is NaN the result is NaN.
+    pub fn max_abs_element(&self) -> f32 {
+        let d0 = self.column(0).abs();
+        let d1 = self.column(1).abs();
+        let d2 = self.column(2).abs();
+        let d3 = self.column(3).abs();
+
+        if (d0.is_nan() | d1.is_nan() | d2.is_nan() | d3.is_nan()).any() {
+            return f32::NAN;
+        }
+
+        d0.simd_max(d1).simd_max(d2.simd_max(d3)).reduce_max()
+    }
+
+    /// Creates a matrix representing a translation along the X axis
+    pub fn translation_x(translation: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 3)] = translation;
+        m
+    }
+
+    /// Creates a matrix representing a translation along the Y axis
+    pub fn translation_y(translation: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(1, 3)] = translation;
+        m
+    }
+
+    /// Creates a matrix representing a translation along the Z axis
+    pub fn translation_z(translation: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(2, 3)] = translation;
+        m
+    }
+
+    /// Creates a matrix representing a translation
+    pub fn translation(translation: Vector3f) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 3)] = translation.x();
+        m[(1, 3)] = translation.y();
+        m[(2, 3)] = translation.z();
+        m
+    }
+
+    /// Creates a matrix representing a scaling along the X axis
+    pub fn scaling_x(scale: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 0)] = scale;
+        m
+    }
+
+    /// Creates a matrix representing a scaling along the Y axis
+    pub fn scaling_y(scale: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(1, 1)] = scale;
+        m
+    }
+
+    /// Creates a matrix representing a scaling along the Z axis
+    pub fn scaling_z(scale: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(2, 2)] = scale;
+        m
+    }
+
+    /// Creates a matrix representing a scaling
+    pub fn scaling(scale: Vector3f) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 0)] = scale.x();
+        m[(1, 1)] = scale.y();
+        m[(2, 2)] = scale.z();
+        m
+    }
+
+    /// Creates a matrix representing a shearing of the X axis along the Y axis, so `x' = x + factor * y`
+    pub fn shear_xy(factor: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 1)] = factor;
+        m
+    }
+
+    /// Creates a matrix representing a shearing of the X axis along the Z axis, so `x' = x + factor * z`
+    pub fn shear_xz(factor: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 2)] = factor;
+        m
+    }
+
+    /// Creates a matrix representing a shearing of the Y axis along the X axis, so `y' = y + factor * x`
+    pub fn shear_yx(factor: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(1, 0)] = factor;
+        m
+    }
+
+    /// Creates a matrix representing a shearing of the Y axis along the Z axis, so `y' = y + factor * z`
+    pub fn shear_yz(factor: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(1, 2)] = factor;
+        m
+    }
+
+    /// Creates a matrix representing a shearing of the Z axis along the X axis, so `z' = z + factor * x`
+    pub fn shear_zx(factor: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(2, 0)] = factor;
+        m
+    }
+
+    /// Creates a matrix representing a shearing of the Z axis along the Y axis, so `z' = z + factor * y`
+    pub fn shear_zy(factor: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(2, 1)] = factor;
+        m
+    }
+
+    /// Creates a matrix representing a shearing along all axes, the factors are named like the individual shear constructors
+    pub fn shear(xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        m[(0, 1)] = xy;
+        m[(0, 2)] = xz;
+        m[(1, 0)] = yx;
+        m[(1, 2)] = yz;
+        m[(2, 0)] = zx;
+        m[(2, 1)] = zy;
+        m
+    }
+
+    /// Creates a matrix with the given vector on the main diagonal and zeros elsewhere
+    #[rustfmt::skip]
+    pub fn from_diagonal(diagonal: Vector4f) -> Self {
+        let [x, y, z, w] = diagonal.to_array();
+
+        Self::from_array([
+            [ x , 0.0, 0.0, 0.0],
+            [0.0,  y , 0.0, 0.0],
+            [0.0, 0.0,  z , 0.0],
+            [0.0, 0.0, 0.0,  w ],
+        ])
+    }
+
+    /// Returns the main diagonal of this matrix
+    #[inline]
+    pub fn diagonal(&self) -> Vector4f {
+        Vector4f::new(self[(0, 0)], self[(1, 1)], self[(2, 2)], self[(3, 3)])
+    }
+
+    /// Creates a matrix representing a rotation around the X axis
+    pub fn rotation_x(angle: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        let (sin, cos) = angle.sin_cos();
+        m[(1, 1)] = cos;
+        m[(2, 1)] = sin;
+        m[(1, 2)] = -sin;
+        m[(2, 2)] = cos;
+        m
+    }
+
+    /// Creates a matrix representing a rotation around the Y axis
+    pub fn rotation_y(angle: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        let (sin, cos) = angle.sin_cos();
+        m[(0, 0)] = cos;
+        m[(2, 0)] = -sin;
+        m[(0, 2)] = sin;
+        m[(2, 2)] = cos;
+        m
+    }
+
+    /// Creates a matrix representing a rotation around the Z axis
+    pub fn rotation_z(angle: f32) -> Self {
+        let mut m = Self::IDENTITY;
+        let (sin, cos) = angle.sin_cos();
+        m[(0, 0)] = cos;
+        m[(0, 1)] = -sin;
+        m[(1, 0)] = sin;
+        m[(1, 1)] = cos;
+        m
+    }
+
+    /// Creates a matrix representing a rotation
+    ///
+    /// The quaternion must be normalized, the resulting matrix rotates vectors the same way as `Quaternion::rotate_vector`
+    pub fn rotation(rotation: Quaternion) -> Self {
+        // (2xx, 2yy, 2zz, 2ww)
+        let sqr = rotation.xyzw() * rotation.xyzw() * 2.0;
+        let xx = sqr.x();
+        let yy = sqr.y();
+        let zz = sqr.z();
+
+        // (2xy, 2xz, 2xw, 2zw)
+        let perm1 = rotation.xxxz() * rotation.yzww() * 2.0;
+        let xy = perm1.x();
+        let xz = perm1.y();
+        let xw = perm1.z();
+        let zw = perm1.w();
+
+        // (2yz, 2yw, 2zw)
+        let perm2 = rotation.yyz() * rotation.zww() * 2.0;
+        let yz = perm2.x();
+        let yw = perm2.y();
+
+        let e00 = 1.0 - yy - zz;
+        let e01 = xy - zw;
+        let e02 = xz + yw;
+
+        let e10 = xy + zw;
+        let e11 = 1.0 - xx - zz;
+        let e12 = yz - xw;
+
+        let e20 = xz - yw;
+        let e21 = yz + xw;
+        let e22 = 1.0 - xx - yy;
+
+        Self::from_array([
+            [e00, e10, e20, 0.0],
+            [e01, e11, e21, 0.0],
+            [e02, e12, e22, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a matrix representing a rotation specified by yaw, pitch and roll angles
+    #[inline]
+    pub fn from_yaw_pitch_roll(yaw: f32, pitch: f32, roll: f32) -> Self {
+        let rot = Quaternion::from_yaw_pitch_roll(yaw, pitch, roll);
+        Self::rotation(rot)
+    }
+
+    /// Creates a matrix representing a transformation specified by scale, rotation and translation, applied in that order
+    pub fn from_scale_rotation_translation(
+        scale: Vector3f,
+        rotation: Quaternion,
+        translation: Vector3f,
+    ) -> Self {
+        let scaling = Self::scaling(scale);
+        let rotation = Self::rotation(rotation);
+        let translation = Self::translation(translation);
+        translation * rotation * scaling
+    }
+
+    /// Creates a matrix representing a rigid transformation specified by rotation and translation, applied in that order
+    ///
+    /// Cheaper than `from_scale_rotation_translation` since no scaling has to be applied
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector3f) -> Self {
+        let mut m = Self::rotation(rotation);
+        m.0[3] = Vector4f::from_v3f(translation, 1.0).0;
+        m
+    }
+
+    /// Decomposes this matrix into scale, rotation and translation, the inverse of `from_scale_rotation_translation`
+    ///
+    /// The matrix must be affine and must not contain shearing. A reflection is represented by a negative X scale.
+    pub fn to_scale_rotation_translation(&self) -> (Vector3f, Quaternion, Vector3f) {
+        let translation = Vector3f::from_simd_truncate(self.column(3));
+
+        let x = Vector3f::from_simd_truncate(self.column(0));
+        let y = Vector3f::from_simd_truncate(self.column(1));
+        let z = Vector3f::from_simd_truncate(self.column(2));
+
+        let sign = if self.affine_determinant() < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        let scale = Vector3f::new(x.len() * sign, y.len(), z.len());
+
+        let rotation = Self::from_array([
+            (x * sign).normalized().extend(0.0).to_array(),
+            y.normalized().extend(0.0).to_array(),
+            z.normalized().extend(0.0).to_array(),
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        (scale, Quaternion::from_matrix(rotation), translation)
+    }
+
+    /// Interpolates between this matrix and rhs by decomposing both into scale, rotation and translation
+    ///
+    /// Scale and translation are interpolated linearly and rotation spherically, so rigid transformations stay rigid.
+    /// Both matrices must satisfy the requirements of `to_scale_rotation_translation`.
+    pub fn interpolate_trs(&self, rhs: &Self, t: f32) -> Self {
+        let (s0, r0, t0) = self.to_scale_rotation_translation();
+        let (s1, r1, t1) = rhs.to_scale_rotation_translation();
+        Self::from_scale_rotation_translation(s0.lerp(s1, t), r0.slerp(r1, t), t0.lerp(t1, t))
+    }
+
+    /// Applies a translation after the transformation of this matrix
+    ///
+    /// Equivalent to `Matrix4x4::translation(translation) * self` (left multiply)
+    #[inline]
+    pub fn then_translate(self, translation: Vector3f) -> Self {
+        Self::translation(translation) * self
+    }
+
+    /// Applies a scaling after the transformation of this matrix
+    ///
+    /// Equivalent to `Matrix4x4::scaling(scale) * self` (left multiply)
+    #[inline]
+    pub fn then_scale(self, scale: Vector3f) -> Self {
+        Self::scaling(scale) * self
+    }
+
+    /// Applies a rotation after the transformation of this matrix
+    ///
+    /// Equivalent to `Matrix4x4::rotation(rotation) * self` (left multiply)
+    #[inline]
+    pub fn then_rotate(self, rotation: Quaternion) -> Self {
+        Self::rotation(rotation) * self
+    }
+
+    /// Applies a translation before the transformation of this matrix
+    ///
+    /// Equivalent to `self * Matrix4x4::translation(translation)` (right multiply)
+    #[inline]
+    pub fn pre_translate(self, translation: Vector3f) -> Self {
+        self * Self::translation(translation)
+    }
+
+    /// Applies a scaling before the transformation of this matrix
+    ///
+    /// Equivalent to `self * Matrix4x4::scaling(scale)` (right multiply)
+    #[inline]
+    pub fn pre_scale(self, scale: Vector3f) -> Self {
+        self * Self::scaling(scale)
+    }
+
+    /// Applies a rotation before the transformation of this matrix
+    ///
+    /// Equivalent to `self * Matrix4x4::rotation(rotation)` (right multiply)
+    #[inline]
+    pub fn pre_rotate(self, rotation: Quaternion) -> Self {
+        self * Self::rotation(rotation)
+    }
+
+    /// Transposes this matrix
+    pub fn transposed(&self) -> Self {
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+        let c3 = self.column(3);
+
+        macro_rules! unpacklo {
+            ($a:expr, $b:expr) => {
+                simd_swizzle!($a, $b, [First(0), Second(0), First(1), Second(1)])
+            };
+        }
+
+        macro_rules! unpackhi {
+            ($a:expr, $b:expr) => {
+                simd_swizzle!($a, $b, [First(2), Second(2), First(3), Second(3)])
+            };
+        }
+
+        macro_rules! movelh {
+            ($a:expr, $b:expr) => {
+                simd_swizzle!($a, $b, [First(0), First(1), Second(0), Second(1)])
+            };
+        }
+
+        macro_rules! movehl {
+            ($a:expr, $b:expr) => {
+                simd_swizzle!($a, $b, [Second(2), Second(3), First(2), First(3)])
+            };
+        }
+
+        // Intel _MM_TRANSPOSE4_PS macro expanded
+        let tmp0 = unpacklo!(c0, c1);
+        let tmp2 = unpacklo!(c2, c3);
+        let tmp1 = unpackhi!(c0, c1);
+        let tmp3 = unpackhi!(c2, c3);
+        let c0 = movelh!(tmp0, tmp2);
+        let c1 = movehl!(tmp2, tmp0);
+        let c2 = movelh!(tmp1, tmp3);
+        let c3 = movehl!(tmp3, tmp1);
+
+        Self([c0, c1, c2, c3])
+    }
+
+    /// Orthonormalizes the upper-left 3x3 part of this matrix using the Gram-Schmidt process,
+    /// preserving translation and the bottom row
+    pub fn orthonormalized(&self) -> Self {
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+        let c3 = self.column(3);
+
+        let x = Vector3f::from_simd_truncate(c0);
+        let y = Vector3f::from_simd_truncate(c1);
+        let z = Vector3f::from_simd_truncate(c2);
+
+        let x = x.normalized();
+        let y = (y - (x * x.dot(y))).normalized();
+        let z = (z - (x * x.dot(z)) - (y * y.dot(z))).normalized();
+
+        let mask = mask32x4::from_array([true, true, true, false]);
+        let c0 = mask.select(x.0, c0);
+        let c1 = mask.select(y.0, c1);
+        let c2 = mask.select(z.0, c2);
+
+        Self([c0, c1, c2, c3])
+    }
+
+    /// Restores orthonormality of the rotation part of this matrix after drift from accumulated multiplications
+    ///
+    /// This is cheaper than `orthonormalized` but only valid for rigid transformations without scaling or reflection.
+    /// The X axis keeps its direction, the Z axis is recomputed as the cross product of the X and Y axes
+    /// and the Y axis as the cross product of the Z and X axes. Translation and the bottom row are preserved.
+    pub fn renormalize_rotation(&mut self) {
+        let x = Vector3f::from_simd_truncate(self.column(0)).normalized();
+        let y = Vector3f::from_simd_truncate(self.column(1));
+        let z = x.cross(y).normalized();
+        let y = z.cross(x);
+
+        let mask = mask32x4::from_array([true, true, true, false]);
+        self.0[0] = mask.select(x.0, self.0[0]);
+        self.0[1] = mask.select(y.0, self.0[1]);
+        self.0[2] = mask.select(z.0, self.0[2]);
+    }
+
+    /// Adds other scaled by scale to this matrix, using a fused multiply-add per column
+    pub fn add_scaled(&mut self, other: &Self, scale: f32) {
+        let scale = f32x4::splat(scale);
+        for (c, other_c) in self.0.iter_mut().zip(other.0) {
+            *c = other_c.mul_add(scale, *c);
+        }
+    }
+
+    /// Calculates the determinant of this matrix
+    pub fn determinant(&self) -> f32 {
+        let _2323 = (self[(2, 2)] * self[(3, 3)]) - (self[(3, 2)] * self[(2, 3)]);
+        let _1323 = (self[(1, 2)] * self[(3, 3)]) - (self[(3, 2)] * self[(1, 3)]);
+        let _1223 = (self[(1, 2)] * self[(2, 3)]) - (self[(2, 2)] * self[(1, 3)]);
+        let _0323 = (self[(0, 2)] * self[(3, 3)]) - (self[(3, 2)] * self[(0, 3)]);
+        let _0223 = (self[(0, 2)] * self[(2, 3)]) - (self[(2, 2)] * self[(0, 3)]);
+        let _0123 = (self[(0, 2)] * self[(1, 3)]) - (self[(1, 2)] * self[(0, 3)]);
+
+        let a = (self[(1, 1)] * _2323) - (self[(2, 1)] * _1323) + (self[(3, 1)] * _1223);
+        let b = (self[(0, 1)] * _2323) - (self[(2, 1)] * _0323) + (self[(3, 1)] * _0223);
+        let c = (self[(0, 1)] * _1323) - (self[(1, 1)] * _0323) + (self[(3, 1)] * _0123);
+        let d = (self[(0, 1)] * _1223) - (self[(1, 1)] * _0223) + (self[(2, 1)] * _0123);
+
+        const SIGN: f32x4 = f32x4::from_array([1.0, -1.0, 1.0, -1.0]);
+        let c0 = self.column(0);
+        let prod = c0 * f32x4::from_array([a, b, c, d]) * SIGN;
+        prod.reduce_sum()
+    }
+
+    /// Calculates the determinant of the upper-left 3x3 part of this matrix
+    ///
+    /// This equals `determinant` only for affine matrices (see `is_affine`), for other matrices the bottom row
+    /// also contributes to the full determinant. A negative result means the transformation contains a reflection.
+    #[inline]
+    pub fn affine_determinant(&self) -> f32 {
+        let x = Vector3f::from_simd_truncate(self.column(0));
+        let y = Vector3f::from_simd_truncate(self.column(1));
+        let z = Vector3f::from_simd_truncate(self.column(2));
+        x.scalar_triple(y, z)
+    }
+
+    // Matrix inverse algorithms from:
+    // https://lxjk.github.io/2017/09/03/Fast-4x4-Matrix-Inverse-with-SSE-SIMD-Explained.html
+
+    /// Calculates the inverse as long as the input matrix is a transform (only translation, rotation, scaling)
+    ///
+    /// The result is meaningless for matrices that are not affine, see `is_affine`
+    pub fn transform_inverse(&self) -> Self {
+        let self_c0 = self.column(0);
+        let self_c1 = self.column(1);
+        let self_c2 = self.column(2);
+        let self_c3 = self.column(3);
+
+        // transpose 3x3, we know m03 = m13 = m23 = 0
+        let t0 = simd_swizzle_0101!(self_c0, self_c1); // 00, 01, 10, 11
+        let t1 = simd_swizzle_2323!(self_c0, self_c1); // 02, 03, 12, 13
+        let c0 = simd_swizzle!(t0, self_c2, [First(0), First(2), Second(0), Second(3)]); // 00, 10, 20, 23(=0)
+        let c1 = simd_swizzle!(t0, self_c2, [First(1), First(3), Second(1), Second(3)]); // 01, 11, 21, 23(=0)
+        let c2 = simd_swizzle!(t1, self_c2, [First(0), First(2), Second(2), Second(3)]); // 02, 12, 22, 23(=0)
+
+        // (SizeSqr(mVec[0]), SizeSqr(mVec[1]), SizeSqr(mVec[2]), 0)
+        let size_sqr = (c0 * c0) + (c1 * c1) + (c2 * c2);
+
+        // optional test to avoid divide by 0
+        let one = f32x4::splat(1.0);
+        let eps = f32x4::splat(f32::EPSILON);
+        // for each component, if(sizeSqr < SMALL_NUMBER) sizeSqr = 1;
+        let mask = f32x4::simd_lt(size_sqr, eps);
+        let size_sqr = mask.select(one, one / size_sqr);
+
+        let c0 = c0 * size_sqr;
+        let c1 = c1 * size_sqr;
+        let c2 = c2 * size_sqr;
+
+        // last line
+        let r3 = {
+            (c0 * simd_swizzle_1!(self_c3, 0))
+                + (c1 * simd_swizzle_1!(self_c3, 1))
+                + (c2 * simd_swizzle_1!(self_c3, 2))
+        };
+        const LAST: f32x4 = f32x4::from_array([0.0, 0.0, 0.0, 1.0]);
+        let c3 = LAST - r3;
+
+        Self([c0, c1, c2, c3])
+    }
+
+    // 2x2 Matrix multiply A*B
+    #[inline]
+    fn mul_mat2(lhs: f32x4, rhs: f32x4) -> f32x4 {
+        let a = lhs * simd_swizzle!(rhs, [0, 3, 0, 3]);
+        let b = simd_swizzle!(lhs, [1, 0, 3, 2]) * simd_swizzle!(rhs, [2, 1, 2, 1]);
+        a + b
+    }
+
+    // 2x2 Matrix adjugate multiply (A#)*B
+    #[inline]
+    fn adj_mul_mat2(lhs: f32x4, rhs: f32x4) -> f32x4 {
+        let a = simd_swizzle!(lhs, [3, 3, 0, 0]) * rhs;
+        let b = simd_swizzle!(lhs, [1, 1, 2, 2]) * simd_swizzle!(rhs, [2, 3, 0, 1]);
+        a - b
+    }
+
+    // 2x2 Matrix multiply adjugate A*(B#)
+    #[inline]
+    fn mul_adj_mat2(lhs: f32x4, rhs: f32x4) -> f32x4 {
+        let a = lhs * simd_swizzle!(rhs, [3, 0, 3, 0]);
+        let b = simd_swizzle!(lhs, [1, 0, 3, 2]) * simd_swizzle!(rhs, [2, 1, 2, 1]);
+        a - b
+    }
+
+    // Returns the adjugate blocks (X#, Y#, Z#, W#) and the determinant, splatted across all lanes
+    fn block_adjugate(&self) -> ([f32x4; 4], f32x4) {
+        let self_c0 = self.column(0);
+        let self_c1 = self.column(1);
+        let self_c2 = self.column(2);
+        let self_c3 = self.column(3);
+
+        // use block matrix method
+        // A is a matrix, then i(A) or iA means inverse of A, A# (or A_ in code) means adjugate of A, |A| (or detA in code) is determinant, tr(A) is trace
 
-        let epsilon = f32x2::splat(epsilon);
+        // sub matrices
+        let a = simd_swizzle_0101!(self_c0, self_c1);
+        let b = simd_swizzle_2323!(self_c0, self_c1);
+        let c = simd_swizzle_0101!(self_c2, self_c3);
+        let d = simd_swizzle_2323!(self_c2, self_c3);
 
-        let c0 = self.column(0);
-        let c1 = self.column(1);
-        let c2 = self.column(2);
+        // determinant as (|A| |B| |C| |D|)
+        let det_sub = ({
+            simd_swizzle!(self_c0, self_c2, [First(0), First(2), Second(0), Second(2)])
+                * simd_swizzle!(self_c1, self_c3, [First(1), First(3), Second(1), Second(3)])
+        }) - ({
+            simd_swizzle!(self_c0, self_c2, [First(1), First(3), Second(1), Second(3)])
+                * simd_swizzle!(self_c1, self_c3, [First(0), First(2), Second(0), Second(2)])
+        });
 
-        let d0 = (c0 - I0).abs();
-        let d1 = (c1 - I1).abs();
-        let d2 = (c2 - I2).abs();
+        let det_a = simd_swizzle_1!(det_sub, 0);
+        let det_b = simd_swizzle_1!(det_sub, 1);
+        let det_c = simd_swizzle_1!(det_sub, 2);
+        let det_d = simd_swizzle_1!(det_sub, 3);
 
-        let lt0 = d0.simd_lt(epsilon).all();
-        let lt1 = d1.simd_lt(epsilon).all();
-        let lt2 = d2.simd_lt(epsilon).all();
+        // let iM = 1/|M| * | X  Y |
+        //                  | Z  W |
 
-        lt0 && lt1 && lt2
-    }
+        // D#C
+        let d_c = Self::adj_mul_mat2(d, c);
+        // A#B
+        let a_b = Self::adj_mul_mat2(a, b);
 
-    /// Creates a matrix representing a translation along the X axis
-    pub fn translation_x(translation: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 2)] = translation;
-        m
-    }
+        // X# = |D|A - B(D#C)
+        let x = (det_d * a) - Self::mul_mat2(b, d_c);
+        // W# = |A|D - C(A#B)
+        let w = (det_a * d) - Self::mul_mat2(c, a_b);
 
-    /// Creates a matrix representing a translation along the Y axis
-    pub fn translation_y(translation: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(1, 2)] = translation;
-        m
-    }
+        // |M| = |A|*|D| + ... (continue later)
+        let det_m = det_a * det_d;
+        // Y# = |B|C - D(A#B)#
+        let y = (det_b * c) - Self::mul_adj_mat2(d, a_b);
+        // Z# = |C|B - A(D#C)#
+        let z = (det_c * b) - Self::mul_adj_mat2(a, d_c);
+        // |M| = |A|*|D| + |B|*|C| ... (continue later)
+        let det_m = det_m + (det_b * det_c);
 
-    /// Creates a matrix representing a translation
-    pub fn translation(translation: Vector2f) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 2)] = translation.x();
-        m[(1, 2)] = translation.y();
-        m
-    }
+        // tr((A#B)(D#C))
+        let tr = a_b * simd_swizzle!(d_c, [0, 2, 1, 3]); // (00, 01, 10, 11) as 2x2 matrix
 
-    /// Creates a matrix representing a scaling along the X axis
-    pub fn scaling_x(scale: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 0)] = scale;
-        m
-    }
+        // |M| = |A|*|D| + |B|*|C| - tr((A#B)(D#C)
+        let det_m = det_m - f32x4::splat(tr.reduce_sum());
 
-    /// Creates a matrix representing a scaling along the Y axis
-    pub fn scaling_y(scale: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(1, 1)] = scale;
-        m
+        ([x, y, z, w], det_m)
     }
 
-    /// Creates a matrix representing a scaling
-    pub fn scaling(scale: Vector2f) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 0)] = scale.x();
-        m[(1, 1)] = scale.y();
-        m
-    }
+    #[inline]
+    fn from_block_adjugate([x, y, z, w]: [f32x4; 4], det_m: f32x4) -> Self {
+        const ADJ_SIGN_MASK: f32x4 = f32x4::from_array([1.0, -1.0, -1.0, 1.0]);
+        // (1/|M|, -1/|M|, -1/|M|, 1/|M|)
+        let r_det_m = ADJ_SIGN_MASK / det_m;
 
-    /// Creates a matrix representing a rotation
-    pub fn rotation(angle: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        let (sin, cos) = angle.sin_cos();
-        m[(0, 0)] = cos;
-        m[(0, 1)] = -sin;
-        m[(1, 0)] = sin;
-        m[(1, 1)] = cos;
-        m
-    }
+        let x = x * r_det_m;
+        let y = y * r_det_m;
+        let z = z * r_det_m;
+        let w = w * r_det_m;
 
-    /// Creates a matrix representing a transformation specified by scale, rotation and translation, applied in that order
-    pub fn from_scale_rotation_translation(
-        scale: Vector2f,
-        rotation: f32,
-        translation: Vector2f,
-    ) -> Self {
-        let scaling = Self::scaling(scale);
-        let rotation = Self::rotation(rotation);
-        let translation = Self::translation(translation);
-        translation * rotation * scaling
-    }
+        // apply adjugate and store, here we combine adjugate shuffle and store shuffle
+        let c0 = simd_swizzle!(x, y, [First(3), First(1), Second(3), Second(1)]);
+        let c1 = simd_swizzle!(x, y, [First(2), First(0), Second(2), Second(0)]);
+        let c2 = simd_swizzle!(z, w, [First(3), First(1), Second(3), Second(1)]);
+        let c3 = simd_swizzle!(z, w, [First(2), First(0), Second(2), Second(0)]);
 
-    /// Calculates the determinant of this matrix
-    #[inline]
-    pub fn determinant(&self) -> f32 {
-        let c0 = Vector2f(self.column(0));
-        let c1 = Vector2f(self.column(1));
-        Vector2f::cross(c0, c1)
+        Self([c0, c1, c2, c3])
     }
 
     /// Calculates the inverse of this matrix
+    ///
+    /// If the matrix is singular the result will contain infinite or NaN elements,
+    /// use `try_inverse` if that case has to be detected
     pub fn inverse(&self) -> Self {
-        let det = self.determinant();
-        let inv_det = 1.0 / det;
+        let (adj, det_m) = self.block_adjugate();
+        Self::from_block_adjugate(adj, det_m)
+    }
 
-        let _e00 = self[(0, 0)];
-        let _e10 = self[(1, 0)];
-        let _e01 = self[(0, 1)];
-        let _e11 = self[(1, 1)];
-        let _e02 = self[(0, 2)];
-        let _e12 = self[(1, 2)];
+    /// Calculates the inverse of this matrix, or returns `None` if the matrix is singular
+    ///
+    /// The matrix counts as singular if its determinant is smaller than epsilon relative to the product of its column lengths,
+    /// so uniformly scaled matrices like `scaling(0.01)` are still invertible
+    pub fn try_inverse(&self) -> Option<Self> {
+        let (adj, det_m) = self.block_adjugate();
+
+        // the product of the column lengths is an upper bound for |M|
+        let mut scale = 1.0;
+        for i in 0..4 {
+            let c = self.column(i);
+            scale *= (c * c).reduce_sum().sqrt();
+        }
 
-        let e00 = _e11 * inv_det;
-        let e10 = -_e01 * inv_det;
-        let e01 = -_e10 * inv_det;
-        let e11 = _e00 * inv_det;
-        let e02 = (_e01 * _e12 - _e02 * _e11) * inv_det;
-        let e12 = (_e02 * _e10 - _e00 * _e12) * inv_det;
+        if det_m[0].abs() > (f32::EPSILON * scale) {
+            Some(Self::from_block_adjugate(adj, det_m))
+        } else {
+            None
+        }
+    }
 
-        Self::new(e00, e10, e01, e11, e02, e12)
+    /// Calculates the transformation of this matrix relative to parent, such that `parent * self.relative_to(parent) == self`
+    ///
+    /// If parent only consists of translation, rotation and scaling, the cheaper `transform_inverse` is used to invert it
+    pub fn relative_to(&self, parent: &Self) -> Self {
+        const EPSILON: f32 = 1e-5;
+
+        let parent_inverse = if parent.is_affine(EPSILON) && parent.has_orthogonal_axes(EPSILON) {
+            parent.transform_inverse()
+        } else {
+            parent.inverse()
+        };
+
+        parent_inverse * *self
+    }
+
+    // Checks whether the columns of the upper-left 3x3 part are mutually orthogonal, ignoring their lengths
+    fn has_orthogonal_axes(&self, epsilon: f32) -> bool {
+        let x = Vector3f::from_simd_truncate(self.column(0)).normalized();
+        let y = Vector3f::from_simd_truncate(self.column(1)).normalized();
+        let z = Vector3f::from_simd_truncate(self.column(2)).normalized();
+
+        let dots = f32x4::from_array([x.dot(y), y.dot(z), z.dot(x), 0.0]);
+        dots.abs().simd_lt(f32x4::splat(epsilon)).all()
     }
 
     /// Linearily interpolates between this matrix and rhs
@@ -1422,125 +4819,425 @@ impl Matrix2x3 {
         let lhs_c0 = lhs.column(0);
         let lhs_c1 = lhs.column(1);
         let lhs_c2 = lhs.column(2);
+        let lhs_c3 = lhs.column(3);
 
         let rhs_c0 = rhs.column(0);
         let rhs_c1 = rhs.column(1);
         let rhs_c2 = rhs.column(2);
+        let rhs_c3 = rhs.column(3);
 
-        let t = f32x2::splat(t);
+        let t = f32x4::splat(t);
         let c0 = lhs_c0 + ((rhs_c0 - lhs_c0) * t);
         let c1 = lhs_c1 + ((rhs_c1 - lhs_c1) * t);
         let c2 = lhs_c2 + ((rhs_c2 - lhs_c2) * t);
+        let c3 = lhs_c3 + ((rhs_c3 - lhs_c3) * t);
+
+        Self([c0, c1, c2, c3])
+    }
+
+    // Linear combination of the columns of this matrix, weighted by the lanes of rhs
+    #[inline]
+    fn mul_column(&self, rhs: f32x4) -> f32x4 {
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+        let c3 = self.column(3);
+
+        let x = simd_swizzle_1!(rhs, 0);
+        let y = simd_swizzle_1!(rhs, 1);
+        let z = simd_swizzle_1!(rhs, 2);
+        let w = simd_swizzle_1!(rhs, 3);
+        (c0 * x) + (c1 * y) + (c2 * z) + (c3 * w)
+    }
+
+    /// Multiples the matrix with a vector while not applying translation
+    pub fn mul_no_translate(&self, rhs: Vector3f) -> Vector3f {
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+
+        let x = simd_swizzle_1!(rhs.0, 0);
+        let y = simd_swizzle_1!(rhs.0, 1);
+        let z = simd_swizzle_1!(rhs.0, 2);
+        Vector3f::from_simd_truncate((c0 * x) + (c1 * y) + (c2 * z))
+    }
+
+    /// Transforms a surface normal by the inverse-transpose of the upper-left 3x3 part of this matrix
+    /// and normalizes the result
+    ///
+    /// For rigid transforms this is equivalent to `mul_no_translate`
+    pub fn transform_normal(&self, normal: Vector3f) -> Vector3f {
+        let c0 = Vector3f::from_simd_truncate(self.column(0));
+        let c1 = Vector3f::from_simd_truncate(self.column(1));
+        let c2 = Vector3f::from_simd_truncate(self.column(2));
+
+        // columns of the cofactor matrix, which is the inverse-transpose scaled by the determinant
+        let cof0 = Vector3f::cross(c1, c2);
+        let cof1 = Vector3f::cross(c2, c0);
+        let cof2 = Vector3f::cross(c0, c1);
+
+        let n = (cof0 * normal.x()) + (cof1 * normal.y()) + (cof2 * normal.z());
+        if c0.dot(cof0) < 0.0 {
+            -n.normalized()
+        } else {
+            n.normalized()
+        }
+    }
+
+    #[rustfmt::skip]
+    fn format_elements(&self, precision: Option<usize>) -> ([[String; 4]; 4], usize) {
+        let (s00, w00) = format_width!(self[(0, 0)], precision);
+        let (s10, w10) = format_width!(self[(1, 0)], precision);
+        let (s20, w20) = format_width!(self[(2, 0)], precision);
+        let (s30, w30) = format_width!(self[(3, 0)], precision);
+
+        let (s01, w01) = format_width!(self[(0, 1)], precision);
+        let (s11, w11) = format_width!(self[(1, 1)], precision);
+        let (s21, w21) = format_width!(self[(2, 1)], precision);
+        let (s31, w31) = format_width!(self[(3, 1)], precision);
+
+        let (s02, w02) = format_width!(self[(0, 2)], precision);
+        let (s12, w12) = format_width!(self[(1, 2)], precision);
+        let (s22, w22) = format_width!(self[(2, 2)], precision);
+        let (s32, w32) = format_width!(self[(3, 2)], precision);
+
+        let (s03, w03) = format_width!(self[(0, 3)], precision);
+        let (s13, w13) = format_width!(self[(1, 3)], precision);
+        let (s23, w23) = format_width!(self[(2, 3)], precision);
+        let (s33, w33) = format_width!(self[(3, 3)], precision);
+
+        let strings = [
+            [s00, s10, s20, s30],
+            [s01, s11, s21, s31],
+            [s02, s12, s22, s32],
+            [s03, s13, s23, s33],
+        ];
+
+        let widths = [
+            w00, w10, w20, w30,
+            w01, w11, w21, w31,
+            w02, w12, w22, w32,
+            w03, w13, w23, w33,
+        ];
+
+        (strings, widths.into_iter().max().unwrap())
+    }
+
+    /// Creates a matrix representing the transformation of looking from a position in a direction
+    ///
+    /// If the direction is parallel to the up vector, a different axis is used as up vector instead
+    pub fn look_to(pos: Vector3f, dir: Vector3f, up: Vector3f) -> Self {
+        let up = up.normalized();
+
+        let f = dir.normalized();
+        let s = Vector3f::cross(up, f);
+        let s = if s.len2() < 1e-12 {
+            // up is parallel to the view direction, pick the axis least aligned with it instead
+            let up = if f.z().abs() < 0.9 {
+                Vector3f::UNIT_Z
+            } else {
+                Vector3f::UNIT_X
+            };
+            Vector3f::cross(up, f).normalized()
+        } else {
+            s.normalized()
+        };
+        let u = Vector3f::cross(f, s);
+
+        let tx = -Vector3f::dot(s, pos);
+        let ty = -Vector3f::dot(u, pos);
+        let tz = -Vector3f::dot(f, pos);
+
+        Self::from_array([
+            [s.x(), u.x(), f.x(), 0.0],
+            [s.y(), u.y(), f.y(), 0.0],
+            [s.z(), u.z(), f.z(), 0.0],
+            [tx, ty, tz, 1.0],
+        ])
+    }
+
+    /// Creates a matrix representing the transformation of looking from a position at a target
+    #[inline]
+    pub fn look_at(pos: Vector3f, target: Vector3f, up: Vector3f) -> Self {
+        Self::look_to(pos, target - pos, up)
+    }
+
+    /// Creates a perspective projection matrix
+    ///
+    /// Constraints:
+    /// - fov_y > 0.0
+    /// - aspect_ration > 0.0
+    /// - near_plane > 1.0
+    /// - far_plane > near_plane
+    ///
+    /// Panics if any constraint is violated, use `try_perspective` to handle invalid parameters
+    pub fn perspective(fov_y: f32, aspect_ratio: f32, near_plane: f32, far_plane: f32) -> Self {
+        match Self::try_perspective(fov_y, aspect_ratio, near_plane, far_plane) {
+            Ok(m) => m,
+            Err(err) => panic!("invalid perspective projection: {err}"),
+        }
+    }
+
+    /// Creates a perspective projection matrix, returning an error if any constraint of `perspective` is violated
+    #[inline]
+    pub fn try_perspective(
+        fov_y: f32,
+        aspect_ratio: f32,
+        near_plane: f32,
+        far_plane: f32,
+    ) -> Result<Self, ProjectionError> {
+        Self::try_perspective_with_depth(fov_y, aspect_ratio, near_plane, far_plane, 0.0, 1.0)
+    }
 
-        Self([c0, c1, c2])
+    /// Creates a perspective projection matrix for a viewport of the given size, using width / height as the aspect ratio
+    ///
+    /// The constraints of `perspective` apply and width and height must be greater than zero,
+    /// panics if any constraint is violated.
+    pub fn perspective_from_size(
+        fov_y: f32,
+        width: f32,
+        height: f32,
+        near_plane: f32,
+        far_plane: f32,
+    ) -> Self {
+        match Self::try_perspective_from_size(fov_y, width, height, near_plane, far_plane) {
+            Ok(m) => m,
+            Err(err) => panic!("invalid perspective projection: {err}"),
+        }
     }
 
-    /// Multiples the matrix with a vector while not applying translation
-    pub fn mul_no_translate(&self, rhs: Vector2f) -> Vector2f {
-        let r0 = self.column(0);
-        let r1 = self.column(1);
+    /// Creates a perspective projection matrix for a viewport of the given size,
+    /// returning an error if any constraint of `perspective_from_size` is violated
+    pub fn try_perspective_from_size(
+        fov_y: f32,
+        width: f32,
+        height: f32,
+        near_plane: f32,
+        far_plane: f32,
+    ) -> Result<Self, ProjectionError> {
+        if width.is_nan() || height.is_nan() || (width <= 0.0) || (height <= 0.0) {
+            return Err(ProjectionError::NonPositiveSize);
+        }
 
-        let x = simd_swizzle!(rhs.0, [0, 0]);
-        let y = simd_swizzle!(rhs.0, [1, 1]);
-        Vector2f((r0 * x) + (r1 * y))
+        Self::try_perspective(fov_y, width / height, near_plane, far_plane)
     }
 
-    /// Converts the matrix into a 4x4 matrix
+    /// Creates a perspective projection matrix that maps the near plane to depth_min and the far plane to depth_max
+    ///
+    /// `perspective` uses the range [0, 1], OpenGL style projections use [-1, 1] and reverse-Z uses [1, 0].
+    /// The constraints of `perspective` apply, panics if any constraint is violated.
+    pub fn perspective_with_depth(
+        fov_y: f32,
+        aspect_ratio: f32,
+        near_plane: f32,
+        far_plane: f32,
+        depth_min: f32,
+        depth_max: f32,
+    ) -> Self {
+        match Self::try_perspective_with_depth(
+            fov_y,
+            aspect_ratio,
+            near_plane,
+            far_plane,
+            depth_min,
+            depth_max,
+        ) {
+            Ok(m) => m,
+            Err(err) => panic!("invalid perspective projection: {err}"),
+        }
+    }
+
+    /// Creates a perspective projection matrix that maps the near plane to depth_min and the far plane to depth_max,
+    /// returning an error if any constraint of `perspective` is violated
     #[rustfmt::skip]
-    pub fn to_matrix4x4(&self) -> Matrix4x4 {
-        let e00 = self[(0, 0)];
-        let e10 = self[(1, 0)];
-        let e01 = self[(0, 1)];
-        let e11 = self[(1, 1)];
-        let e02 = self[(0, 2)];
-        let e12 = self[(1, 2)];
+    pub fn try_perspective_with_depth(
+        fov_y: f32,
+        aspect_ratio: f32,
+        near_plane: f32,
+        far_plane: f32,
+        depth_min: f32,
+        depth_max: f32,
+    ) -> Result<Self, ProjectionError> {
+        if fov_y.is_nan() || (fov_y <= 0.0) {
+            return Err(ProjectionError::NonPositiveFov);
+        }
+        if aspect_ratio.is_nan() || (aspect_ratio <= 0.0) {
+            return Err(ProjectionError::NonPositiveAspectRatio);
+        }
+        if near_plane.is_nan() || (near_plane <= 1.0) {
+            return Err(ProjectionError::NearPlaneTooClose);
+        }
+        if far_plane.is_nan() || (far_plane <= near_plane) {
+            return Err(ProjectionError::FarPlaneNotBeyondNear);
+        }
 
-        Matrix4x4::from_array([
-            [e00, e10, 0.0, 0.0],
-            [e01, e11, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [e02, e12, 0.0, 1.0],
-        ])
+        let (sin, cos) = (fov_y * 0.5).sin_cos();
+        let h = cos / sin;
+        let w = h / aspect_ratio;
+        // solves r + z / near_plane = depth_min and r + z / far_plane = depth_max
+        let r = ((depth_max * far_plane) - (depth_min * near_plane)) / (far_plane - near_plane);
+        let z = (depth_min - r) * near_plane;
+
+        Ok(Self::from_array([
+            [ w , 0.0, 0.0, 0.0],
+            [0.0,  h , 0.0, 0.0],
+            [0.0, 0.0,  r , 1.0],
+            [0.0, 0.0,  z , 0.0]
+        ]))
     }
 
-    #[rustfmt::skip]
-    fn format_elements(&self) -> ([[String; 2]; 3], usize) {
-        let (s00, w00) = format_width!(self[(0, 0)]);
-        let (s10, w10) = format_width!(self[(1, 0)]);
+    /// Creates an orthographic projection matrix
+    ///
+    /// The Z coordinate is passed through unchanged, use `orthographic_offcenter` if depth has to be mapped
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32) -> Self {
+        let e00 = 2.0 / (right - left);
+        let e11 = 2.0 / (top - bottom);
+        let e03 = (right + left) / (left - right);
+        let e13 = (top + bottom) / (bottom - top);
 
-        let (s01, w01) = format_width!(self[(0, 1)]);
-        let (s11, w11) = format_width!(self[(1, 1)]);
+        Self::from_array([
+            [e00, 0.0, 0.0, 0.0],
+            [0.0, e11, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [e03, e13, 0.0, 1.0],
+        ])
+    }
 
-        let (s02, w02) = format_width!(self[(0, 2)]);
-        let (s12, w12) = format_width!(self[(1, 2)]);
+    /// Creates an orthographic projection matrix that maps the depth range [near_plane, far_plane] to [0, 1],
+    /// the same clip range as `perspective`
+    pub fn orthographic_offcenter(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near_plane: f32,
+        far_plane: f32,
+    ) -> Self {
+        let e00 = 2.0 / (right - left);
+        let e11 = 2.0 / (top - bottom);
+        let e22 = 1.0 / (far_plane - near_plane);
+        let e03 = (right + left) / (left - right);
+        let e13 = (top + bottom) / (bottom - top);
+        let e23 = near_plane / (near_plane - far_plane);
 
-        let strings = [
-            [s00, s10],
-            [s01, s11],
-            [s02, s12],
-        ];
+        Self::from_array([
+            [e00, 0.0, 0.0, 0.0],
+            [0.0, e11, 0.0, 0.0],
+            [0.0, 0.0, e22, 0.0],
+            [e03, e13, e23, 1.0],
+        ])
+    }
 
-        let widths = [
-            w00, w10,
-            w01, w11,
-            w02, w12,
-        ];
+    /// Creates a centered orthographic projection matrix
+    pub fn orthographic_centered(width: f32, height: f32) -> Self {
+        let e00 = 2.0 / width;
+        let e11 = 2.0 / height;
 
-        (strings, widths.into_iter().max().unwrap())
+        Self::from_array([
+            [e00, 0.0, 0.0, 0.0],
+            [0.0, e11, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
     }
 }
-impl Index<(usize, usize)> for Matrix2x3 {
+impl Index<(usize, usize)> for Matrix4x4 {
     type Output = f32;
 
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         &self.0[index.1][index.0]
     }
 }
-impl IndexMut<(usize, usize)> for Matrix2x3 {
+impl IndexMut<(usize, usize)> for Matrix4x4 {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         &mut self.0[index.1][index.0]
     }
 }
-impl Mul<Vector2f> for Matrix2x3 {
-    type Output = Vector2f;
+impl Mul<Vector4f> for Matrix4x4 {
+    type Output = Vector4f;
 
-    fn mul(self, rhs: Vector2f) -> Self::Output {
+    fn mul(self, rhs: Vector4f) -> Self::Output {
+        Vector4f(self.mul_column(rhs.0))
+    }
+}
+impl Mul<Vector3f> for Matrix4x4 {
+    type Output = Vector3f;
+
+    fn mul(self, rhs: Vector3f) -> Self::Output {
         let c0 = self.column(0);
         let c1 = self.column(1);
         let c2 = self.column(2);
+        let c3 = self.column(3);
 
-        let x = simd_swizzle!(rhs.0, [0, 0]);
-        let y = simd_swizzle!(rhs.0, [1, 1]);
-        Vector2f((c0 * x) + (c1 * y) + c2)
+        let x = simd_swizzle_1!(rhs.0, 0);
+        let y = simd_swizzle_1!(rhs.0, 1);
+        let z = simd_swizzle_1!(rhs.0, 2);
+        Vector3f::from_simd_truncate((c0 * x) + (c1 * y) + (c2 * z) + c3)
     }
 }
-impl Mul for Matrix2x3 {
+impl Mul for Matrix4x4 {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let lhs_c0 = self.column(0);
-        let lhs_c1 = self.column(1);
-        let lhs_c2 = self.column(2);
+        let c0 = self.mul_column(rhs.column(0));
+        let c1 = self.mul_column(rhs.column(1));
+        let c2 = self.mul_column(rhs.column(2));
+        let c3 = self.mul_column(rhs.column(3));
 
-        let c0 = { (lhs_c0 * f32x2::splat(rhs[(0, 0)])) + (lhs_c1 * f32x2::splat(rhs[(1, 0)])) };
-        let c1 = { (lhs_c0 * f32x2::splat(rhs[(0, 1)])) + (lhs_c1 * f32x2::splat(rhs[(1, 1)])) };
-        let c2 = {
-            (lhs_c0 * f32x2::splat(rhs[(0, 2)])) + (lhs_c1 * f32x2::splat(rhs[(1, 2)])) + lhs_c2
-        };
+        Self([c0, c1, c2, c3])
+    }
+}
+impl TryFrom<&[f32]> for Matrix4x4 {
+    type Error = TryFromSliceError;
+
+    /// Creates a matrix from 16 elements in column-major order
+    fn try_from(slice: &[f32]) -> Result<Self, Self::Error> {
+        let a: [f32; 16] = slice_to_array(slice)?;
+        Ok(Self::from_array([
+            [a[0], a[1], a[2], a[3]],
+            [a[4], a[5], a[6], a[7]],
+            [a[8], a[9], a[10], a[11]],
+            [a[12], a[13], a[14], a[15]],
+        ]))
+    }
+}
+impl Mul<Matrix2x3> for Matrix4x4 {
+    type Output = Self;
 
-        Self([c0, c1, c2])
+    #[inline]
+    fn mul(self, rhs: Matrix2x3) -> Self::Output {
+        self * rhs.to_matrix4x4()
     }
 }
-impl Debug for Matrix2x3 {
+
+impl Mul<Matrix4x4> for Matrix2x3 {
+    type Output = Matrix4x4;
+
+    #[inline]
+    fn mul(self, rhs: Matrix4x4) -> Self::Output {
+        self.to_matrix4x4() * rhs
+    }
+}
+
+impl From<Matrix2x3> for Matrix4x4 {
+    fn from(other: Matrix2x3) -> Self {
+        other.to_matrix4x4()
+    }
+}
+impl Debug for Matrix4x4 {
     #[rustfmt::skip]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (strings, width) = self.format_elements();
-        let s = format!("Matrix2x3(\
-            \n\t{:<width$}, {:<width$}, {:<width$},\
-            \n\t{:<width$}, {:<width$}, {:<width$},\
+        let (strings, width) = self.format_elements(f.precision());
+        let s = format!("Matrix4x4(\
+            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
+            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
+            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
+            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
             \n)",
-            strings[0][0], strings[1][0], strings[2][0],
-            strings[0][1], strings[1][1], strings[2][1],
+            strings[0][0], strings[1][0], strings[2][0], strings[3][0],
+            strings[0][1], strings[1][1], strings[2][1], strings[3][1],
+            strings[0][2], strings[1][2], strings[2][2], strings[3][2],
+            strings[0][3], strings[1][3], strings[2][3], strings[3][3],
             width = width
         );
 
@@ -1548,17 +5245,19 @@ impl Debug for Matrix2x3 {
         write!(f, "{}", s)
     }
 }
-impl Display for Matrix2x3 {
+impl Display for Matrix4x4 {
     #[rustfmt::skip]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (strings, width) = self.format_elements();
+        let (strings, width) = self.format_elements(f.precision());
         let s = format!("\
-            |{:<width$}   {:<width$}   {:<width$}|\n\
-            |{:<width$}   {:<width$}   {:<width$}|\n\
-            |{:<width$}   {:<width$}   {:<width$}|",
-            strings[0][0], strings[1][0], strings[2][0],
-            strings[0][1], strings[1][1], strings[2][1],
-            0.0          , 0.0          , 1.0          ,
+            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|\n\
+            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|\n\
+            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|\n\
+            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|",
+            strings[0][0], strings[1][0], strings[2][0], strings[3][0],
+            strings[0][1], strings[1][1], strings[2][1], strings[3][1],
+            strings[0][2], strings[1][2], strings[2][2], strings[3][2],
+            strings[0][3], strings[1][3], strings[2][3], strings[3][3],
             width = width
         );
 
@@ -1567,776 +5266,1696 @@ impl Display for Matrix2x3 {
     }
 }
 
-/// Column-major 4x4 matrix, indexed as [row, column]
-#[derive(Clone, Copy, PartialEq)]
-#[repr(C, align(16))]
-pub struct Matrix4x4([f32x4; 4]);
-impl Matrix4x4 {
-    /// A matrix representing no transformation
-    pub const IDENTITY: Self = Self([
-        f32x4::from_array([1.0, 0.0, 0.0, 0.0]),
-        f32x4::from_array([0.0, 1.0, 0.0, 0.0]),
-        f32x4::from_array([0.0, 0.0, 1.0, 0.0]),
-        f32x4::from_array([0.0, 0.0, 0.0, 1.0]),
-    ]);
-
-    /// Creates a new matrix from individual elements
-    #[rustfmt::skip]
-    pub const fn new(
-        e00: f32, e10: f32, e20: f32, e30: f32, // Column 0
-        e01: f32, e11: f32, e21: f32, e31: f32, // Column 1
-        e02: f32, e12: f32, e22: f32, e32: f32, // Column 2
-        e03: f32, e13: f32, e23: f32, e33: f32, // Column 3
-    ) -> Self {
-        Self([
-            f32x4::from_array([e00, e10, e20, e30]),
-            f32x4::from_array([e01, e11, e21, e31]),
-            f32x4::from_array([e02, e12, e22, e32]),
-            f32x4::from_array([e03, e13, e23, e33]),
-        ])
-    }
-
-    /// Creates a new matrix from the given array
-    #[inline]
-    pub const fn from_array(array: [[f32; 4]; 4]) -> Self {
-        Self([
-            f32x4::from_array(array[0]),
-            f32x4::from_array(array[1]),
-            f32x4::from_array(array[2]),
-            f32x4::from_array(array[3]),
-        ])
-    }
-
-    /// Converts the matrix into an array
-    #[inline]
-    pub const fn to_array(&self) -> [[f32; 4]; 4] {
-        [
-            self.0[0].to_array(),
-            self.0[1].to_array(),
-            self.0[2].to_array(),
-            self.0[3].to_array(),
-        ]
-    }
-
-    #[inline]
-    const fn column(&self, index: usize) -> f32x4 {
-        self.0[index]
-    }
-
-    /// Checks whether this matrix is the identity matrix, up to a certain error
-    pub fn is_identity(&self, epsilon: f32) -> bool {
-        const I0: f32x4 = f32x4::from_array([1.0, 0.0, 0.0, 0.0]);
-        const I1: f32x4 = f32x4::from_array([0.0, 1.0, 0.0, 0.0]);
-        const I2: f32x4 = f32x4::from_array([0.0, 0.0, 1.0, 0.0]);
-        const I3: f32x4 = f32x4::from_array([0.0, 0.0, 0.0, 1.0]);
-
-        let epsilon = f32x4::splat(epsilon);
-
-        let c0 = self.column(0);
-        let c1 = self.column(1);
-        let c2 = self.column(2);
-        let c3 = self.column(3);
-
-        let d0 = (c0 - I0).abs();
-        let d1 = (c1 - I1).abs();
-        let d2 = (c2 - I2).abs();
-        let d3 = (c3 - I3).abs();
-
-        let lt0 = d0.simd_lt(epsilon).all();
-        let lt1 = d1.simd_lt(epsilon).all();
-        let lt2 = d2.simd_lt(epsilon).all();
-        let lt3 = d3.simd_lt(epsilon).all();
-
-        lt0 && lt1 && lt2 && lt3
+/// A ray starting at an origin and extending infinitely in a direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    /// The point the ray starts at
+    pub origin: Vector3f,
+    /// The direction the ray extends in
+    pub direction: Vector3f,
+}
+impl Ray {
+    /// Creates a new ray from the given origin and direction
+    #[inline]
+    pub const fn new(origin: Vector3f, direction: Vector3f) -> Self {
+        Self { origin, direction }
     }
 
-    /// Creates a matrix representing a translation along the X axis
-    pub fn translation_x(translation: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 3)] = translation;
-        m
+    /// Calculates the point at the given distance along the ray, in multiples of the direction
+    #[inline]
+    pub fn at(&self, t: f32) -> Vector3f {
+        self.direction
+            .mul_add(Vector3f::from_scalar(t), self.origin)
     }
+}
 
-    /// Creates a matrix representing a translation along the Y axis
-    pub fn translation_y(translation: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(1, 3)] = translation;
-        m
+/// An axis-aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    /// The corner with the smallest coordinates
+    pub min: Vector3f,
+    /// The corner with the largest coordinates
+    pub max: Vector3f,
+}
+impl Aabb {
+    /// Creates a new bounding box from the given corners
+    #[inline]
+    pub const fn new(min: Vector3f, max: Vector3f) -> Self {
+        Self { min, max }
     }
 
-    /// Creates a matrix representing a translation along the Z axis
-    pub fn translation_z(translation: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(2, 3)] = translation;
-        m
+    /// Intersects a ray with this bounding box, returning the distances along the ray at which it enters and exits the box
+    ///
+    /// The entry distance is negative if the ray starts inside the box.
+    /// Returns `None` if the ray misses the box or the box lies entirely behind the ray.
+    /// A ray lying in the plane of a face touches the box.
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<(f32, f32)> {
+        // slab method, directions parallel to a slab produce infinite distances
+        let inv_dir = ray.direction.recip();
+        let t1 = (self.min - ray.origin) * inv_dir;
+        let t2 = (self.max - ray.origin) * inv_dir;
+
+        // a parallel ray starting exactly on a slab boundary computes 0 * inf = NaN,
+        // it stays inside that slab so the axis must not constrain the result
+        let on_boundary = t1.0.simd_ne(t1.0) | t2.0.simd_ne(t2.0);
+        let t_near = on_boundary.select(f32x4::splat(f32::NEG_INFINITY), t1.min(t2).0);
+        let t_far = on_boundary.select(f32x4::splat(f32::INFINITY), t1.max(t2).0);
+
+        let t_enter = Vector3f(t_near).max_element();
+        let t_exit = Vector3f(t_far).min_element();
+
+        if (t_exit >= t_enter) && (t_exit >= 0.0) {
+            Some((t_enter, t_exit))
+        } else {
+            None
+        }
     }
 
-    /// Creates a matrix representing a translation
-    pub fn translation(translation: Vector3f) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 3)] = translation.x();
-        m[(1, 3)] = translation.y();
-        m[(2, 3)] = translation.z();
-        m
+    /// Returns the 8 corners of this bounding box
+    ///
+    /// Bit 0 of the index selects the X, bit 1 the Y and bit 2 the Z coordinate, with a cleared bit selecting `min`
+    /// and a set bit selecting `max`, so the first corner is `min` and the last corner is `max`
+    pub fn corners(&self) -> [Vector3f; 8] {
+        std::array::from_fn(|i| {
+            let mask =
+                mask32x4::from_array([(i & 0b001) != 0, (i & 0b010) != 0, (i & 0b100) != 0, false]);
+            Vector3f(mask.select(self.max.0, self.min.0))
+        })
     }
 
-    /// Creates a matrix representing a scaling along the X axis
-    pub fn scaling_x(scale: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 0)] = scale;
-        m
+    /// Returns the point inside this bounding box that is closest to p
+    ///
+    /// Points inside the box are returned unchanged
+    #[inline]
+    pub fn closest_point(&self, p: Vector3f) -> Vector3f {
+        p.clamp(self.min, self.max)
     }
+}
 
-    /// Creates a matrix representing a scaling along the Y axis
-    pub fn scaling_y(scale: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(1, 1)] = scale;
-        m
+/// A plane consisting of all points p for which `normal.dot(p) + d == 0`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    /// The normal vector of the plane, pointing towards the positive half-space
+    pub normal: Vector3f,
+    /// The signed distance of the origin from the plane, in multiples of the normal length
+    pub d: f32,
+}
+impl Plane {
+    /// Creates a new plane from the given normal and offset
+    #[inline]
+    pub const fn new(normal: Vector3f, d: f32) -> Self {
+        Self { normal, d }
     }
 
-    /// Creates a matrix representing a scaling along the Z axis
-    pub fn scaling_z(scale: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(2, 2)] = scale;
-        m
+    /// Creates a new plane with the given normal that passes through point
+    #[inline]
+    pub fn from_point_normal(point: Vector3f, normal: Vector3f) -> Self {
+        Self::new(normal, -normal.dot(point))
     }
 
-    /// Creates a matrix representing a scaling
-    pub fn scaling(scale: Vector3f) -> Self {
-        let mut m = Self::IDENTITY;
-        m[(0, 0)] = scale.x();
-        m[(1, 1)] = scale.y();
-        m[(2, 2)] = scale.z();
-        m
+    /// Scales the plane equation so the normal has unit length
+    #[inline]
+    pub fn normalized(self) -> Self {
+        let len = self.normal.len();
+        if len == 0.0 {
+            self
+        } else {
+            Self::new(self.normal / len, self.d / len)
+        }
     }
 
-    /// Creates a matrix representing a rotation around the X axis
-    pub fn rotation_x(angle: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        let (sin, cos) = angle.sin_cos();
-        m[(1, 1)] = cos;
-        m[(2, 1)] = sin;
-        m[(1, 2)] = -sin;
-        m[(2, 2)] = cos;
-        m
+    /// Calculates the signed distance of p from the plane, positive on the side the normal points to
+    ///
+    /// The result is only a true distance if the plane is normalized
+    #[inline]
+    pub fn signed_distance(&self, p: Vector3f) -> f32 {
+        self.normal.dot(p) + self.d
     }
+}
 
-    /// Creates a matrix representing a rotation around the Y axis
-    pub fn rotation_y(angle: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        let (sin, cos) = angle.sin_cos();
-        m[(0, 0)] = cos;
-        m[(2, 0)] = -sin;
-        m[(0, 2)] = sin;
-        m[(2, 2)] = cos;
-        m
+/// A view frustum bounded by six planes whose normals point inwards
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    /// The left, right, bottom, top, near and far planes, in that order
+    pub planes: [Plane; 6],
+}
+impl Frustum {
+    /// Extracts the frustum planes from a view-projection matrix, using the Gribb/Hartmann method
+    ///
+    /// The clip space convention of `Matrix4x4::perspective` is assumed,
+    /// meaning -w <= x <= w, -w <= y <= w and 0 <= z <= w for points inside the frustum
+    pub fn from_matrix(m: &Matrix4x4) -> Self {
+        let r0 = m.row(0);
+        let r1 = m.row(1);
+        let r2 = m.row(2);
+        let r3 = m.row(3);
+
+        let plane = |v: Vector4f| Plane::new(v.xyz(), v.w()).normalized();
+        Self {
+            planes: [
+                plane(r3 + r0),
+                plane(r3 - r0),
+                plane(r3 + r1),
+                plane(r3 - r1),
+                plane(r2),
+                plane(r3 - r2),
+            ],
+        }
     }
 
-    /// Creates a matrix representing a rotation around the Z axis
-    pub fn rotation_z(angle: f32) -> Self {
-        let mut m = Self::IDENTITY;
-        let (sin, cos) = angle.sin_cos();
-        m[(0, 0)] = cos;
-        m[(0, 1)] = -sin;
-        m[(1, 0)] = sin;
-        m[(1, 1)] = cos;
-        m
+    /// Checks whether p lies inside the frustum or on its boundary
+    #[inline]
+    pub fn contains_point(&self, p: Vector3f) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p) >= 0.0)
     }
 
-    /// Creates a matrix representing a rotation
-    pub fn rotation(rotation: Quaternion) -> Self {
-        let sqr = rotation.xyzw() * rotation.xyzw() * 2.0;
-        let xx = sqr.x();
-        let yy = sqr.y();
-        let zz = sqr.z();
-
-        let perm1 = rotation.xxxz() * rotation.yzww() * 2.0;
-        let xy = perm1.x();
-        let xz = perm1.y();
-        let xw = perm1.z();
-        let zw = perm1.w();
+    /// Checks whether a bounding box intersects or lies inside the frustum
+    ///
+    /// This is conservative, boxes close to the frustum edges may be reported as intersecting even if they are not
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        let zero = f32x4::splat(0.0);
+        self.planes.iter().all(|plane| {
+            // the corner furthest along the plane normal
+            let mask = plane.normal.0.simd_ge(zero);
+            let p = Vector3f(mask.select(aabb.max.0, aabb.min.0));
+            plane.signed_distance(p) >= 0.0
+        })
+    }
 
-        let perm2 = rotation.yyz() * rotation.zww() * 2.0;
-        let yz = perm2.x();
-        let yw = perm2.y();
+    /// Checks whether a sphere intersects or lies inside the frustum
+    ///
+    /// This is conservative, spheres close to the frustum edges may be reported as intersecting even if they are not
+    #[inline]
+    pub fn intersects_sphere(&self, center: Vector3f, radius: f32) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(center) >= -radius)
+    }
+}
 
-        let e00 = 1.0 - yy - zz;
-        let e01 = xy - zw;
-        let e02 = xz + yw;
+/// Types that can be interpolated, allowing code like keyframe sampling to be generic over the representation
+pub trait Interpolate {
+    /// Interpolates between a and b, resulting in a for t = 0 and b for t = 1
+    fn interpolate(a: Self, b: Self, t: f32) -> Self;
+}
 
-        let e10 = xy + zw;
-        let e11 = 1.0 - xx - zz;
-        let e12 = yz - xw;
+macro_rules! impl_interpolate {
+    ($t:ty) => {
+        impl Interpolate for $t {
+            /// Interpolates linearily, see `lerp`
+            #[inline]
+            fn interpolate(a: Self, b: Self, t: f32) -> Self {
+                a.lerp(b, t)
+            }
+        }
+    };
+}
 
-        let e20 = xz - yw;
-        let e21 = yz + xw;
-        let e22 = 1.0 - xx - yy;
+impl_interpolate!(Vector2f);
+impl_interpolate!(Vector3f);
+impl_interpolate!(Vector4f);
+impl_interpolate!(Vector2i);
+impl_interpolate!(Vector3i);
+impl_interpolate!(Vector4i);
 
-        Self::from_array([
-            [e00, e10, e20, 0.0],
-            [e01, e11, e21, 0.0],
-            [e02, e12, e22, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
+impl Interpolate for Quaternion {
+    /// Interpolates spherically, see `Quaternion::slerp`
+    #[inline]
+    fn interpolate(a: Self, b: Self, t: f32) -> Self {
+        a.slerp(b, t)
     }
+}
 
-    /// Creates a matrix representing a rotation specified by yaw, pitch and roll angles
+impl Interpolate for Matrix4x4 {
+    /// Interpolates the decomposed scale, rotation and translation, see `Matrix4x4::interpolate_trs`
     #[inline]
-    pub fn from_yaw_pitch_roll(yaw: f32, pitch: f32, roll: f32) -> Self {
-        let rot = Quaternion::from_yaw_pitch_roll(yaw, pitch, roll);
-        Self::rotation(rot)
+    fn interpolate(a: Self, b: Self, t: f32) -> Self {
+        a.interpolate_trs(&b, t)
     }
+}
 
-    /// Creates a matrix representing a transformation specified by scale, rotation and translation, applied in that order
-    pub fn from_scale_rotation_translation(
-        scale: Vector3f,
-        rotation: Quaternion,
-        translation: Vector3f,
-    ) -> Self {
-        let scaling = Self::scaling(scale);
-        let rotation = Self::rotation(rotation);
-        let translation = Self::translation(translation);
-        translation * rotation * scaling
-    }
+macro_rules! assert_layout {
+    ($t:ty, $size:literal, $align:literal) => {
+        const _: () = assert!(std::mem::size_of::<$t>() == $size);
+        const _: () = assert!(std::mem::align_of::<$t>() == $align);
+    };
+}
 
-    /// Transposes this matrix
-    pub fn transposed(&self) -> Self {
-        let c0 = self.column(0);
-        let c1 = self.column(1);
-        let c2 = self.column(2);
-        let c3 = self.column(3);
+assert_layout!(Vector2f, 8, 8);
+assert_layout!(Vector3f, 16, 16);
+assert_layout!(Vector4f, 16, 16);
+assert_layout!(Vector2i, 8, 8);
+assert_layout!(Vector3i, 16, 16);
+assert_layout!(Vector4i, 16, 16);
+assert_layout!(Vector2u, 8, 8);
+assert_layout!(Vector3u, 16, 16);
+assert_layout!(Vector4u, 16, 16);
+assert_layout!(Quaternion, 16, 16);
+assert_layout!(Matrix2x3, 24, 8);
+assert_layout!(Matrix4x4, 64, 16);
 
-        macro_rules! unpacklo {
-            ($a:expr, $b:expr) => {
-                simd_swizzle!($a, $b, [First(0), Second(0), First(1), Second(1)])
-            };
-        }
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
 
-        macro_rules! unpackhi {
-            ($a:expr, $b:expr) => {
-                simd_swizzle!($a, $b, [First(2), Second(2), First(3), Second(3)])
-            };
-        }
+// All lanes, including the padding lanes of the 3-component vectors, are plain initialized values
+// for which every bit pattern is valid, so casting from arbitrary bytes is sound.
+// A non-zero padding lane only breaks calculations, not memory safety.
+macro_rules! impl_bytemuck {
+    ($t:ty) => {
+        #[cfg(feature = "bytemuck")]
+        unsafe impl Pod for $t {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl Zeroable for $t {}
+    };
+}
 
-        macro_rules! movelh {
-            ($a:expr, $b:expr) => {
-                simd_swizzle!($a, $b, [First(0), First(1), Second(0), Second(1)])
-            };
-        }
+impl_bytemuck!(Vector2f);
+impl_bytemuck!(Vector3f);
+impl_bytemuck!(Vector4f);
+impl_bytemuck!(Vector2i);
+impl_bytemuck!(Vector3i);
+impl_bytemuck!(Vector4i);
+impl_bytemuck!(Vector2u);
+impl_bytemuck!(Vector3u);
+impl_bytemuck!(Vector4u);
+impl_bytemuck!(Quaternion);
+impl_bytemuck!(Matrix2x3);
+impl_bytemuck!(Matrix4x4);
 
-        macro_rules! movehl {
-            ($a:expr, $b:expr) => {
-                simd_swizzle!($a, $b, [Second(2), Second(3), First(2), First(3)])
-            };
+#[allow(non_camel_case_types)]
+#[cfg(feature = "short_names")]
+mod short_names {
+    use super::*;
+
+    /// A vector with 2 f32 components
+    pub type v2f = Vector2f;
+    /// A vector with 3 f32 components
+    pub type v3f = Vector3f;
+    /// A vector with 4 f32 components
+    pub type v4f = Vector4f;
+
+    /// A vector with 2 i32 components
+    pub type v2i = Vector2i;
+    /// A vector with 3 i32 components
+    pub type v3i = Vector3i;
+    /// A vector with 4 i32 components
+    pub type v4i = Vector4i;
+
+    /// A vector with 2 boolean components
+    pub type v2b = Vector2b;
+    /// A vector with 3 boolean components
+    pub type v3b = Vector3b;
+    /// A vector with 4 boolean components
+    pub type v4b = Vector4b;
+
+    /// A vector with 2 u32 components
+    pub type v2u = Vector2u;
+    /// A vector with 3 u32 components
+    pub type v3u = Vector3u;
+    /// A vector with 4 u32 components
+    pub type v4u = Vector4u;
+
+    /// A quaternion
+    pub type quat = Quaternion;
+    /// Column-major 2x3 matrix, indexed as [row, column]
+    pub type mat3 = Matrix2x3;
+    /// Column-major 4x4 matrix, indexed as [row, column]
+    pub type mat4 = Matrix4x4;
+}
+
+#[cfg(feature = "short_names")]
+pub use short_names::*;
+
+include!(concat!(env!("OUT_DIR"), "/swizzle.rs"));
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use super::*;
+    use test::{black_box, Bencher};
+
+    // Deterministic LCG so property tests are reproducible, yields values in [-2, 2)
+    fn random_f32(seed: &mut u32) -> f32 {
+        *seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        (((*seed >> 8) as f32) / ((1u32 << 24) as f32)) * 4.0 - 2.0
+    }
+
+    fn random_matrix(seed: &mut u32) -> Matrix4x4 {
+        let mut a = [[0.0; 4]; 4];
+        for e in a.iter_mut().flatten() {
+            *e = random_f32(seed);
         }
+        Matrix4x4::from_array(a)
+    }
 
-        // Intel _MM_TRANSPOSE4_PS macro expanded
-        let tmp0 = unpacklo!(c0, c1);
-        let tmp2 = unpacklo!(c2, c3);
-        let tmp1 = unpackhi!(c0, c1);
-        let tmp3 = unpackhi!(c2, c3);
-        let c0 = movelh!(tmp0, tmp2);
-        let c1 = movehl!(tmp2, tmp0);
-        let c2 = movelh!(tmp1, tmp3);
-        let c3 = movehl!(tmp3, tmp1);
+    #[test]
+    fn block_inverse_determinant_matches_determinant() {
+        let mut seed = 7;
+        for _ in 0..100 {
+            let m = random_matrix(&mut seed);
+            let det = m.determinant();
 
-        Self([c0, c1, c2, c3])
+            let (_, det_m) = m.block_adjugate();
+            assert!((det_m[0] - det).abs() <= 1e-4 * det.abs().max(1.0));
+            assert_eq!(det_m[0].signum(), det.signum());
+
+            let inv_det = m.inverse().determinant();
+            assert_eq!(inv_det.signum(), det.signum());
+            if det.abs() > 0.1 {
+                assert!((inv_det * det - 1.0).abs() < 1e-3);
+            }
+        }
     }
 
-    /// Calculates the determinant of this matrix
-    pub fn determinant(&self) -> f32 {
-        let _2323 = (self[(2, 2)] * self[(3, 3)]) - (self[(3, 2)] * self[(2, 3)]);
-        let _1323 = (self[(1, 2)] * self[(3, 3)]) - (self[(3, 2)] * self[(1, 3)]);
-        let _1223 = (self[(1, 2)] * self[(2, 3)]) - (self[(2, 2)] * self[(1, 3)]);
-        let _0323 = (self[(0, 2)] * self[(3, 3)]) - (self[(3, 2)] * self[(0, 3)]);
-        let _0223 = (self[(0, 2)] * self[(2, 3)]) - (self[(2, 2)] * self[(0, 3)]);
-        let _0123 = (self[(0, 2)] * self[(1, 3)]) - (self[(1, 2)] * self[(0, 3)]);
+    #[test]
+    fn aabb_ray_slab_cases() {
+        let b = Aabb::new(Vector3f::ZERO, Vector3f::ONE);
+        let hit =
+            |origin: Vector3f, direction: Vector3f| b.intersect_ray(&Ray::new(origin, direction));
 
-        let a = (self[(1, 1)] * _2323) - (self[(2, 1)] * _1323) + (self[(3, 1)] * _1223);
-        let b = (self[(0, 1)] * _2323) - (self[(2, 1)] * _0323) + (self[(3, 1)] * _0223);
-        let c = (self[(0, 1)] * _1323) - (self[(1, 1)] * _0323) + (self[(3, 1)] * _0123);
-        let d = (self[(0, 1)] * _1223) - (self[(1, 1)] * _0223) + (self[(2, 1)] * _0123);
+        assert_eq!(
+            hit(Vector3f::new(-1.0, 0.5, 0.5), Vector3f::UNIT_X),
+            Some((1.0, 2.0))
+        );
+        assert_eq!(hit(Vector3f::new(-1.0, 1.5, 0.5), Vector3f::UNIT_X), None);
+        assert_eq!(hit(Vector3f::new(2.0, 0.5, 0.5), Vector3f::UNIT_X), None);
+        assert_eq!(
+            hit(Vector3f::new(0.5, 0.5, 0.5), Vector3f::UNIT_Z),
+            Some((-0.5, 0.5))
+        );
+        assert_eq!(
+            hit(Vector3f::new(-1.0, -1.0, 0.5), Vector3f::new(1.0, 1.0, 0.0)),
+            Some((1.0, 2.0))
+        );
 
-        const SIGN: f32x4 = f32x4::from_array([1.0, -1.0, 1.0, -1.0]);
-        let c0 = self.column(0);
-        let prod = c0 * f32x4::from_array([a, b, c, d]) * SIGN;
-        prod.reduce_sum()
+        // rays lying in the plane of a face
+        assert_eq!(
+            hit(Vector3f::new(-1.0, 0.0, 0.5), Vector3f::UNIT_X),
+            Some((1.0, 2.0))
+        );
+        assert_eq!(
+            hit(Vector3f::new(-1.0, 1.0, 1.0), Vector3f::UNIT_X),
+            Some((1.0, 2.0))
+        );
+        assert_eq!(
+            hit(Vector3f::new(0.5, 0.0, 3.0), -Vector3f::UNIT_Z),
+            Some((2.0, 3.0))
+        );
+        assert_eq!(hit(Vector3f::new(-1.0, 0.0, 1.5), Vector3f::UNIT_X), None);
     }
 
-    // Matrix inverse algorithms from:
-    // https://lxjk.github.io/2017/09/03/Fast-4x4-Matrix-Inverse-with-SSE-SIMD-Explained.html
+    #[test]
+    fn component_gcd_and_abs_diff() {
+        assert_eq!(Vector2i::new(6, 9).component_gcd(), 3);
+        assert_eq!(Vector3i::ZERO.component_gcd(), 0);
+        assert_eq!(Vector3i::new(i32::MIN, 0, 0).component_gcd(), 1 << 31);
+        assert_eq!(Vector2i::new(i32::MIN, i32::MAX).component_gcd(), 1);
 
-    /// Calculates the inverse as long as the input matrix is a transform (only translation, rotation, scaling)
-    pub fn transform_inverse(&self) -> Self {
-        let self_c0 = self.column(0);
-        let self_c1 = self.column(1);
-        let self_c2 = self.column(2);
-        let self_c3 = self.column(3);
+        // reducing a grid direction to its primitive step
+        let d = Vector3i::new(-4, 8, 12);
+        assert_eq!(d / (d.component_gcd() as i32), Vector3i::new(-1, 2, 3));
 
-        // transpose 3x3, we know m03 = m13 = m23 = 0
-        let t0 = simd_swizzle_0101!(self_c0, self_c1); // 00, 01, 10, 11
-        let t1 = simd_swizzle_2323!(self_c0, self_c1); // 02, 03, 12, 13
-        let c0 = simd_swizzle!(t0, self_c2, [First(0), First(2), Second(0), Second(3)]); // 00, 10, 20, 23(=0)
-        let c1 = simd_swizzle!(t0, self_c2, [First(1), First(3), Second(1), Second(3)]); // 01, 11, 21, 23(=0)
-        let c2 = simd_swizzle!(t1, self_c2, [First(0), First(2), Second(2), Second(3)]); // 02, 12, 22, 23(=0)
+        assert_eq!(
+            Vector2i::new(-3, 5).abs_diff(Vector2i::new(4, -5)),
+            Vector2i::new(7, 10)
+        );
+    }
 
-        // (SizeSqr(mVec[0]), SizeSqr(mVec[1]), SizeSqr(mVec[2]), 0)
-        let size_sqr = (c0 * c0) + (c1 * c1) + (c2 * c2);
+    #[test]
+    fn to_array4_exposes_padding() {
+        assert_eq!(
+            Vector3f::new(1.0, 2.0, 3.0).to_array4(),
+            [1.0, 2.0, 3.0, 0.0]
+        );
+        assert_eq!(Vector3f::new(1.0, 2.0, 3.0).recip().to_array4()[3], 0.0);
+        let padding = (-Vector3f::new(1.0, 2.0, 3.0)).to_array4()[3];
+        assert_eq!(padding, 0.0);
+        assert!(padding.is_sign_negative());
+
+        assert_eq!(Vector3i::new(1, 2, 3).to_array4(), [1, 2, 3, 0]);
+        assert_eq!((-Vector3i::new(1, 2, 3)).to_array4(), [-1, -2, -3, 0]);
+        assert_eq!(
+            (!Vector3u::ZERO).to_array4(),
+            [u32::MAX, u32::MAX, u32::MAX, 0]
+        );
+    }
 
-        // optional test to avoid divide by 0
-        let one = f32x4::splat(1.0);
-        let eps = f32x4::splat(f32::EPSILON);
-        // for each component, if(sizeSqr < SMALL_NUMBER) sizeSqr = 1;
-        let mask = f32x4::simd_lt(size_sqr, eps);
-        let size_sqr = mask.select(one, one / size_sqr);
+    #[test]
+    fn quantize_across_cell_boundaries() {
+        assert_eq!(
+            Vector3f::new(1.99, -0.01, 0.0).quantize(2.0),
+            Vector3i::new(0, -1, 0)
+        );
+        assert_eq!(
+            Vector3f::new(2.01, 0.01, -2.0).quantize(2.0),
+            Vector3i::new(1, 0, -1)
+        );
+        assert_eq!(
+            Vector3f::new(-2.01, 0.0, 0.0).quantize(2.0),
+            Vector3i::new(-2, 0, 0)
+        );
+        assert_eq!(
+            Vector3f::new(1e12, -1e12, 0.0).quantize(1.0),
+            Vector3i::new(i32::MAX, i32::MIN, 0)
+        );
+    }
 
-        let c0 = c0 * size_sqr;
-        let c1 = c1 * size_sqr;
-        let c2 = c2 * size_sqr;
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn quantize_rejects_zero_cell_size() {
+        Vector3f::ONE.quantize(0.0);
+    }
+
+    #[test]
+    fn reductions_ignore_padding() {
+        let v = Vector3f::new(-1.0, -2.0, -3.0);
+        assert_eq!(v.min_element(), -3.0);
+        assert_eq!(v.max_element(), -1.0);
+        assert_eq!(v.element_sum(), -6.0);
+        assert_eq!(v.element_product(), -6.0);
+
+        let v = Vector3i::new(2, 3, 4);
+        assert_eq!(v.min_element(), 2);
+        assert_eq!(v.element_product(), 24);
+        assert_eq!(Vector2f::new(2.0, 3.0).element_product(), 6.0);
+    }
+
+    #[test]
+    fn rotate_vector_quarter_turn() {
+        let q = Quaternion::from_angle_z(std::f32::consts::FRAC_PI_2);
+        assert!(q
+            .rotate_vector(Vector3f::UNIT_X)
+            .abs_diff_eq(Vector3f::UNIT_Y, 1e-6));
+        assert!((q * 3.0)
+            .rotate_vector_unnormalized(Vector3f::UNIT_X)
+            .abs_diff_eq(Vector3f::UNIT_Y, 1e-6));
+    }
+
+    #[test]
+    fn orthographic_offcenter_maps_depth() {
+        let m = Matrix4x4::orthographic_offcenter(-1.0, 3.0, -2.0, 2.0, 0.5, 10.5);
+        let near = m * Vector3f::new(-1.0, -2.0, 0.5);
+        let far = m * Vector3f::new(3.0, 2.0, 10.5);
+        let mid = m * Vector3f::new(1.0, 0.0, 5.5);
+        assert!(near.abs_diff_eq(Vector3f::new(-1.0, -1.0, 0.0), 1e-6));
+        assert!(far.abs_diff_eq(Vector3f::new(1.0, 1.0, 1.0), 1e-6));
+        assert!((mid.z() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn manhattan_and_chebyshev_distances() {
+        let a = Vector3f::ZERO;
+        let b = Vector3f::ONE;
+        assert_eq!(a.distance_manhattan(b), 3.0);
+        assert_eq!(a.distance_chebyshev(b), 1.0);
+        assert!((a.dist(b) - 3f32.sqrt()).abs() < 1e-6);
+
+        let a = Vector2i::new(3, -1);
+        let b = Vector2i::new(0, 3);
+        assert_eq!(a.distance_manhattan(b), 7);
+        assert_eq!(a.distance_chebyshev(b), 4);
+    }
+
+    #[test]
+    fn orthonormalized_fixes_skewed_rotation() {
+        let mut m = Matrix4x4::rotation(Quaternion::from_yaw_pitch_roll(0.3, 0.5, 0.7))
+            .then_translate(Vector3f::new(1.0, 2.0, 3.0));
+        m[(0, 1)] += 0.05;
+        m[(2, 0)] -= 0.03;
+        m[(1, 1)] *= 1.1;
+        assert!(!m.is_orthogonal(1e-3));
+
+        let o = m.orthonormalized();
+        let x = o.col(0).xyz();
+        let y = o.col(1).xyz();
+        let z = o.col(2).xyz();
+        for c in [x, y, z] {
+            assert!((c.len() - 1.0).abs() < 1e-5);
+        }
+        assert!(x.dot(y).abs() < 1e-5);
+        assert!(x.dot(z).abs() < 1e-5);
+        assert!(y.dot(z).abs() < 1e-5);
+        assert_eq!(o.col(3), m.col(3));
+        assert_eq!(o.row(3), m.row(3));
+    }
+
+    #[test]
+    fn from_angle_to_angle_round_trip() {
+        for v in [
+            Vector2f::new(-3.0, 2.0),
+            Vector2f::new(0.5, -4.0),
+            Vector2f::UNIT_Y,
+        ] {
+            assert!(Vector2f::from_angle(v.to_angle()).abs_diff_eq(v.normalized(), 1e-6));
+        }
+    }
 
-        // last line
-        let r3 = {
-            (c0 * simd_swizzle_1!(self_c3, 0))
-                + (c1 * simd_swizzle_1!(self_c3, 1))
-                + (c2 * simd_swizzle_1!(self_c3, 2))
-        };
-        const LAST: f32x4 = f32x4::from_array([0.0, 0.0, 0.0, 1.0]);
-        let c3 = LAST - r3;
+    #[test]
+    fn transform_normal_under_non_uniform_scale() {
+        let m = Matrix4x4::scaling(Vector3f::new(3.0, 0.5, -2.0))
+            .then_rotate(Quaternion::from_yaw_pitch_roll(0.2, 0.3, 0.4))
+            .then_translate(Vector3f::ONE);
+        let normal = Vector3f::new(-1.0, 1.0, 2.0);
+        let tangent = Vector3f::new(1.0, 1.0, 0.0);
+        assert_eq!(normal.dot(tangent), 0.0);
+
+        let n = m.transform_normal(normal);
+        let t = m.mul_no_translate(tangent);
+        assert!((n.len() - 1.0).abs() < 1e-6);
+        assert!(n.dot(t).abs() < 1e-5);
+
+        let r = Matrix4x4::rotation(Quaternion::from_angle_x(0.7));
+        let normal = normal.normalized();
+        assert!(r
+            .transform_normal(normal)
+            .abs_diff_eq(r.mul_no_translate(normal), 1e-6));
+    }
+
+    #[test]
+    fn slerp_to_perturbed_antipode_stays_near() {
+        let q = Quaternion::from_yaw_pitch_roll(0.3, 1.1, -0.4);
+        for k in 0..30 {
+            let eps = 1e-8 * 1.5f32.powi(k);
+            let r = -q + Quaternion::new(eps, -eps, eps * 0.5, 0.0);
+            for t in [0.0, 0.25, 0.5, 1.0] {
+                let s = q.slerp(r, t);
+                assert!(s.is_normalized(1e-4), "{eps} {t} {s}");
+                // -q + eps is the same rotation as q up to eps, so the short arc never leaves q
+                assert!(
+                    s.abs_diff_eq_rotation(&q, 1e-5 + (eps * 4.0)),
+                    "{eps} {t} {s}"
+                );
+            }
+        }
+    }
 
-        Self([c0, c1, c2, c3])
+    fn mul_reference(a: &Matrix4x4, b: &Matrix4x4) -> Matrix4x4 {
+        let mut m = Matrix4x4::IDENTITY;
+        for row in 0..4 {
+            for col in 0..4 {
+                m[(row, col)] = (0..4).map(|k| a[(row, k)] * b[(k, col)]).sum();
+            }
+        }
+        m
     }
 
-    // 2x2 Matrix multiply A*B
-    #[inline]
-    fn mul_mat2(lhs: f32x4, rhs: f32x4) -> f32x4 {
-        let a = lhs * simd_swizzle!(rhs, [0, 3, 0, 3]);
-        let b = simd_swizzle!(lhs, [1, 0, 3, 2]) * simd_swizzle!(rhs, [2, 1, 2, 1]);
-        a + b
+    // The kernel Matrix4x4 multiplication used before switching to lane broadcasts, kept as a benchmark baseline
+    fn mul_splat(a: &Matrix4x4, b: &Matrix4x4) -> Matrix4x4 {
+        let column = |col: usize| {
+            (a.column(0) * f32x4::splat(b[(0, col)]))
+                + (a.column(1) * f32x4::splat(b[(1, col)]))
+                + (a.column(2) * f32x4::splat(b[(2, col)]))
+                + (a.column(3) * f32x4::splat(b[(3, col)]))
+        };
+        Matrix4x4([column(0), column(1), column(2), column(3)])
     }
 
-    // 2x2 Matrix adjugate multiply (A#)*B
-    #[inline]
-    fn adj_mul_mat2(lhs: f32x4, rhs: f32x4) -> f32x4 {
-        let a = simd_swizzle!(lhs, [3, 3, 0, 0]) * rhs;
-        let b = simd_swizzle!(lhs, [1, 1, 2, 2]) * simd_swizzle!(rhs, [2, 3, 0, 1]);
-        a - b
+    #[test]
+    fn matrix_mul_matches_reference() {
+        let a = Matrix4x4::new(
+            1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+        );
+        let b = Matrix4x4::from_diagonal(Vector4f::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(a * b, mul_reference(&a, &b));
+        assert_eq!(b * a, mul_reference(&b, &a));
+
+        let mut seed = 5;
+        for _ in 0..100 {
+            let a = random_matrix(&mut seed);
+            let b = random_matrix(&mut seed);
+            assert!((a * b).abs_diff_eq(&mul_reference(&a, &b), 1e-5));
+            assert_eq!(a * b, mul_splat(&a, &b));
+        }
     }
 
-    // 2x2 Matrix multiply adjugate A*(B#)
-    #[inline]
-    fn mul_adj_mat2(lhs: f32x4, rhs: f32x4) -> f32x4 {
-        let a = lhs * simd_swizzle!(rhs, [3, 0, 3, 0]);
-        let b = simd_swizzle!(lhs, [1, 0, 3, 2]) * simd_swizzle!(rhs, [2, 1, 2, 1]);
-        a - b
+    #[bench]
+    fn bench_matrix_mul(bencher: &mut Bencher) {
+        let mut seed = 5;
+        let a = random_matrix(&mut seed);
+        let b = random_matrix(&mut seed);
+        bencher.iter(|| black_box(a) * black_box(b));
     }
 
-    /// Calculates the inverse of this matrix
-    pub fn inverse(&self) -> Self {
-        let self_c0 = self.column(0);
-        let self_c1 = self.column(1);
-        let self_c2 = self.column(2);
-        let self_c3 = self.column(3);
+    #[bench]
+    fn bench_matrix_mul_splat(bencher: &mut Bencher) {
+        let mut seed = 5;
+        let a = random_matrix(&mut seed);
+        let b = random_matrix(&mut seed);
+        bencher.iter(|| mul_splat(black_box(&a), black_box(&b)));
+    }
 
-        // use block matrix method
-        // A is a matrix, then i(A) or iA means inverse of A, A# (or A_ in code) means adjugate of A, |A| (or detA in code) is determinant, tr(A) is trace
+    #[cfg(feature = "color_fields")]
+    #[test]
+    fn srgb_known_values() {
+        let s = Vector3f::new(0.5, 0.0, 1.0).linear_to_srgb();
+        assert!(s.abs_diff_eq(Vector3f::new(0.735_356_9, 0.0, 1.0), 1e-5));
 
-        // sub matrices
-        let a = simd_swizzle_0101!(self_c0, self_c1);
-        let b = simd_swizzle_2323!(self_c0, self_c1);
-        let c = simd_swizzle_0101!(self_c2, self_c3);
-        let d = simd_swizzle_2323!(self_c2, self_c3);
+        let l = Vector4f::new(0.735_356_9, 0.5, 0.04, 0.3).srgb_to_linear();
+        assert!((l.x() - 0.5).abs() < 1e-5);
+        assert!((l.y() - 0.214_041).abs() < 1e-5);
+        assert!((l.z() - (0.04 / 12.92)).abs() < 1e-7);
+        assert_eq!(l.w(), 0.3);
+    }
 
-        // determinant as (|A| |B| |C| |D|)
-        let det_sub = ({
-            simd_swizzle!(self_c0, self_c2, [First(0), First(2), Second(0), Second(2)])
-                * simd_swizzle!(self_c1, self_c3, [First(1), First(3), Second(1), Second(3)])
-        }) - ({
-            simd_swizzle!(self_c0, self_c2, [First(1), First(3), Second(1), Second(3)])
-                * simd_swizzle!(self_c1, self_c3, [First(0), First(2), Second(0), Second(2)])
-        });
+    #[cfg(feature = "color_fields")]
+    #[test]
+    fn hsv_gray_and_round_trip() {
+        let gray = Vector3f::new(0.5, 0.5, 0.5);
+        assert_eq!(gray.rgb_to_hsv(), Vector3f::new(0.0, 0.0, 0.5));
+        assert_eq!(Vector3f::new(0.7, 0.0, 0.5).hsv_to_rgb(), gray);
+        assert_eq!(Vector3f::ZERO.rgb_to_hsv(), Vector3f::ZERO);
+
+        let green = Vector3f::new(0.0, 1.0, 0.0).rgb_to_hsv();
+        assert!(green.abs_diff_eq(Vector3f::new(1.0 / 3.0, 1.0, 1.0), 1e-6));
+        // Hue just below the wraparound
+        assert!((Vector3f::new(1.0, 0.0, 0.5).rgb_to_hsv().x() - (11.0 / 12.0)).abs() < 1e-6);
+
+        let mut seed = 9;
+        for _ in 0..200 {
+            let c = Vector3f::new(
+                random_f32(&mut seed).abs() / 2.0,
+                random_f32(&mut seed).abs() / 2.0,
+                random_f32(&mut seed).abs() / 2.0,
+            );
+            assert!(c.rgb_to_hsv().hsv_to_rgb().abs_diff_eq(c, 1e-5));
+        }
+    }
 
-        let det_a = simd_swizzle_1!(det_sub, 0);
-        let det_b = simd_swizzle_1!(det_sub, 1);
-        let det_c = simd_swizzle_1!(det_sub, 2);
-        let det_d = simd_swizzle_1!(det_sub, 3);
+    #[test]
+    fn barycentric_round_trip() {
+        let a = Vector3f::new(1.0, 0.0, 2.0);
+        let b = Vector3f::new(3.0, 1.0, 0.0);
+        let c = Vector3f::new(0.0, 4.0, 1.0);
+        for (w, p) in [
+            (Vector3f::UNIT_X, a),
+            (Vector3f::UNIT_Y, b),
+            (Vector3f::UNIT_Z, c),
+        ] {
+            assert!(Vector3f::barycentric(p, a, b, c).abs_diff_eq(w, 1e-5));
+        }
 
-        // let iM = 1/|M| * | X  Y |
-        //                  | Z  W |
+        let w = Vector3f::new(0.2, 0.3, 0.5);
+        let r = Vector3f::barycentric(Vector3f::from_barycentric(w, a, b, c), a, b, c);
+        assert!(r.abs_diff_eq(w, 1e-5));
+        assert!((r.element_sum() - 1.0).abs() < 1e-6);
+
+        let (a, b, c) = (a.xy(), b.xy(), c.xy());
+        let r = Vector2f::barycentric(Vector2f::from_barycentric(w, a, b, c), a, b, c);
+        assert!(r.abs_diff_eq(w, 1e-5));
+        assert!((r.element_sum() - 1.0).abs() < 1e-6);
+
+        // degenerate triangles fall back to the first corner
+        assert_eq!(Vector2f::barycentric(b, a, a, a), Vector3f::UNIT_X);
+        assert_eq!(
+            Vector3f::barycentric(Vector3f::ONE, Vector3f::ZERO, Vector3f::ZERO, Vector3f::ONE),
+            Vector3f::UNIT_X
+        );
+    }
 
-        // D#C
-        let d_c = Self::adj_mul_mat2(d, c);
-        // A#B
-        let a_b = Self::adj_mul_mat2(a, b);
+    #[test]
+    fn rotation_matrix_matches_quaternion() {
+        use std::f32::consts::*;
 
-        // X# = |D|A - B(D#C)
-        let x = (det_d * a) - Self::mul_mat2(b, d_c);
-        // W# = |A|D - C(A#B)
-        let w = (det_a * d) - Self::mul_mat2(c, a_b);
+        let axes = [
+            Vector3f::UNIT_X,
+            Vector3f::UNIT_Y,
+            Vector3f::UNIT_Z,
+            Vector3f::new(1.0, 1.0, 1.0),
+            Vector3f::new(-0.3, 2.0, 0.7),
+        ];
+        let angles = [0.0, 0.4, FRAC_PI_2, PI, -2.5, 5.0];
+        for axis in axes {
+            for angle in angles {
+                let q = Quaternion::from_axis_angle_normalized(axis, angle);
+                let m = Matrix4x4::rotation(q);
+                for v in [Vector3f::UNIT_X, Vector3f::UNIT_Y, Vector3f::UNIT_Z] {
+                    assert!(
+                        (m * v).abs_diff_eq(q.rotate_vector(v), 1e-5),
+                        "{axis} {angle} {v}"
+                    );
+                }
+            }
+        }
+    }
 
-        // |M| = |A|*|D| + ... (continue later)
-        let det_m = det_a * det_d;
-        // Y# = |B|C - D(A#B)#
-        let y = (det_b * c) - Self::mul_adj_mat2(d, a_b);
-        // Z# = |C|B - A(D#C)#
-        let z = (det_c * b) - Self::mul_adj_mat2(a, d_c);
-        // |M| = |A|*|D| + |B|*|C| ... (continue later)
-        let det_m = det_m + (det_b * det_c);
+    #[test]
+    fn integer_pow_keeps_padding() {
+        assert_eq!(Vector3i::new(2, -3, 4).pow(0).to_array4(), [1, 1, 1, 0]);
+        assert_eq!(Vector3i::new(2, -3, 4).pow(3).to_array4(), [8, -27, 64, 0]);
+        assert_eq!(Vector2i::new(2, -3).pow(0), Vector2i::ONE);
+        assert_eq!(Vector2i::new(2, -3).pow(2), Vector2i::new(4, 9));
+    }
 
-        // tr((A#B)(D#C))
-        let tr = a_b * simd_swizzle!(d_c, [0, 2, 1, 3]); // (00, 01, 10, 11) as 2x2 matrix
+    #[test]
+    fn slerp_takes_short_arc() {
+        use std::f32::consts::*;
 
-        // |M| = |A|*|D| + |B|*|C| - tr((A#B)(D#C)
-        let det_m = det_m - f32x4::splat(tr.reduce_sum());
+        let q = Quaternion::IDENTITY.slerp(Quaternion::from_angle_z(1.5 * PI), 0.5);
+        assert!(
+            q.abs_diff_eq_rotation(&Quaternion::from_angle_z(-0.25 * PI), 1e-5),
+            "{q}"
+        );
+        let v = q.rotate_vector(Vector3f::UNIT_X);
+        assert!(v.abs_diff_eq(Vector3f::new(1.0, -1.0, 0.0).normalized(), 1e-5));
+
+        let v = Vector3f::UNIT_X.slerp(Vector3f::new(-1.0, -1.0, 0.0).normalized(), 0.5);
+        assert!(v.y() < 0.0);
+    }
+
+    #[test]
+    fn rotation_around_pivot() {
+        let pivot = Vector2f::new(3.0, -2.0);
+        let m = Matrix2x3::rotation_around(1.2, pivot);
+        assert!((m * pivot).abs_diff_eq(pivot, 1e-6));
+
+        let composed = Matrix2x3::translation(pivot)
+            * Matrix2x3::rotation(1.2)
+            * Matrix2x3::translation(-pivot);
+        assert!(m.abs_diff_eq(&composed, 1e-5));
+
+        let t = Vector2f::new(1.0, 2.0);
+        let expected = Matrix2x3::from_scale_rotation_translation(Vector2f::ONE, 0.4, t);
+        assert!(Matrix2x3::from_angle_translation(0.4, t).abs_diff_eq(&expected, 1e-6));
+    }
+
+    #[test]
+    fn euclid_division_wraps_into_period() {
+        let v = Vector3f::new(-0.25, -0.25, -0.25).rem_euclid(Vector3f::ONE);
+        assert_eq!(v, Vector3f::new(0.75, 0.75, 0.75));
+        let v = Vector3f::new(-0.25, 1.25, -7.0).rem_euclid_scalar(1.0);
+        assert_eq!(v.to_array4(), [0.75, 0.25, 0.0, 0.0]);
+        assert_eq!(
+            Vector3f::new(1.0, 2.0, 3.0)
+                .div_euclid_scalar(2.0)
+                .to_array4(),
+            [0.0, 1.0, 1.0, 0.0]
+        );
 
-        const ADJ_SIGN_MASK: f32x4 = f32x4::from_array([1.0, -1.0, -1.0, 1.0]);
-        // (1/|M|, -1/|M|, -1/|M|, 1/|M|)
-        let r_det_m = ADJ_SIGN_MASK / det_m;
+        for a in [-7.5f32, -1.0, -0.25, 0.0, 3.3, 9.0] {
+            for b in [-2.0f32, -0.5, 0.5, 3.0] {
+                let v = Vector4f::from_scalar(a);
+                assert_eq!(
+                    v.rem_euclid_scalar(b),
+                    Vector4f::from_scalar(a.rem_euclid(b))
+                );
+                assert_eq!(
+                    v.div_euclid_scalar(b),
+                    Vector4f::from_scalar(a.div_euclid(b))
+                );
+            }
+        }
+    }
 
-        let x = x * r_det_m;
-        let y = y * r_det_m;
-        let z = z * r_det_m;
-        let w = w * r_det_m;
+    #[test]
+    fn look_to_with_parallel_up() {
+        let m = Matrix4x4::look_to(
+            Vector3f::new(0.0, 5.0, 0.0),
+            -Vector3f::UNIT_Y,
+            Vector3f::UNIT_Y,
+        );
+        assert!((0..16).all(|i| m[(i % 4, i / 4)].is_finite()));
+        assert!(m.is_affine(1e-5));
+        assert!(m.is_orthogonal(1e-5));
+        assert!((m.determinant() - 1.0).abs() < 1e-5);
+        // the origin is 5 units in front of the camera
+        assert!((m * Vector3f::ZERO).abs_diff_eq(Vector3f::new(0.0, 0.0, 5.0), 1e-5));
+
+        let m = Matrix4x4::look_to(Vector3f::ZERO, Vector3f::UNIT_Z, Vector3f::UNIT_Z);
+        assert!((m.determinant() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn recip_and_div_keep_padding_zero() {
+        let v = Vector3f::new(2.0, 4.0, 8.0);
+        let r = v.recip();
+        assert_eq!(r, Vector3f::new(0.5, 0.25, 0.125));
+        assert_eq!(r.to_array4()[3], 0.0);
+
+        assert_eq!(
+            (v / Vector3f::new(2.0, 2.0, 2.0)).to_array4(),
+            [1.0, 2.0, 4.0, 0.0]
+        );
+        assert_eq!((v / 0.5).to_array4(), [4.0, 8.0, 16.0, 0.0]);
+        assert_eq!((v / Vector3f::ZERO).to_array4()[3], 0.0);
+    }
+
+    fn angle_between(a: Vector3f, b: Vector3f) -> f32 {
+        a.cross(b).len().atan2(a.dot(b))
+    }
+
+    #[test]
+    fn rotate_toward_is_capped() {
+        let a = Vector2f::new(2.0, 0.0);
+        let r = a.rotate_toward(Vector2f::new(0.0, -1.0), 0.25);
+        assert!((r.to_angle() + 0.25).abs() < 1e-6);
+        assert!((r.len() - 2.0).abs() < 1e-6);
+        // antiparallel targets rotate counter-clockwise
+        let r = a.rotate_toward(Vector2f::new(-1.0, 0.0), 0.25);
+        assert!((r.to_angle() - 0.25).abs() < 1e-6);
+        // targets within reach are hit exactly
+        assert_eq!(
+            a.rotate_toward(Vector2f::new(0.0, 3.0), 2.0),
+            Vector2f::new(0.0, 2.0)
+        );
 
-        // apply adjugate and store, here we combine adjugate shuffle and store shuffle
-        let c0 = simd_swizzle!(x, y, [First(3), First(1), Second(3), Second(1)]);
-        let c1 = simd_swizzle!(x, y, [First(2), First(0), Second(2), Second(0)]);
-        let c2 = simd_swizzle!(z, w, [First(3), First(1), Second(3), Second(1)]);
-        let c3 = simd_swizzle!(z, w, [First(2), First(0), Second(2), Second(0)]);
+        let v = Vector3f::new(1.0, 2.0, 3.0);
+        let t = Vector3f::new(-3.0, 1.0, 0.5);
+        let r = v.rotate_toward(t, 0.3);
+        assert!((angle_between(v, r) - 0.3).abs() < 1e-5);
+        assert!(((angle_between(v, t) - angle_between(r, t)) - 0.3).abs() < 1e-5);
+        assert!((r.len() - v.len()).abs() < 1e-5);
+        assert!(v.cross(t).dot(r).abs() < 1e-5);
+
+        let r = v.rotate_toward(-v * 2.0, 0.3);
+        assert!((angle_between(v, r) - 0.3).abs() < 1e-4);
+        assert!((r.len() - v.len()).abs() < 1e-5);
+        assert!(v.rotate_toward(v * 3.0, 0.1).abs_diff_eq(v, 1e-5));
+    }
+
+    #[test]
+    fn frustum_culling() {
+        // camera at z = -10 looking along +Z, so the visible depth range is [-8, 90]
+        let proj = Matrix4x4::perspective(1.2, 1.5, 2.0, 100.0);
+        let view = Matrix4x4::look_to(
+            Vector3f::new(0.0, 0.0, -10.0),
+            Vector3f::UNIT_Z,
+            Vector3f::UNIT_Y,
+        );
+        let view_proj = proj * view;
+        let f = Frustum::from_matrix(&view_proj);
+
+        assert!(f.contains_point(Vector3f::ZERO));
+        assert!(f.contains_point(Vector3f::new(0.0, 0.0, 89.0)));
+        assert!(!f.contains_point(Vector3f::new(0.0, 0.0, -9.0)));
+        assert!(!f.contains_point(Vector3f::new(0.0, 0.0, 91.0)));
+        assert!(!f.contains_point(Vector3f::new(50.0, 0.0, 0.0)));
+
+        // inside, straddling the near plane, outside behind the near plane and outside to the right
+        assert!(f.intersects_sphere(Vector3f::ZERO, 1.0));
+        assert!(f.intersects_sphere(Vector3f::new(0.0, 0.0, -9.0), 2.0));
+        assert!(!f.intersects_sphere(Vector3f::new(0.0, 0.0, -9.0), 0.5));
+        assert!(!f.intersects_sphere(Vector3f::new(50.0, 0.0, 0.0), 5.0));
+
+        let aabb = |min: [f32; 3], max: [f32; 3]| {
+            Aabb::new(Vector3f::from_array(min), Vector3f::from_array(max))
+        };
+        assert!(f.intersects_aabb(&aabb([-1.0, -1.0, -1.0], [1.0, 1.0, 1.0])));
+        assert!(f.intersects_aabb(&aabb([-1.0, -1.0, -20.0], [1.0, 1.0, -5.0])));
+        assert!(f.intersects_aabb(&aabb([9.0, -1.0, -1.0], [12.0, 1.0, 1.0])));
+        assert!(!f.intersects_aabb(&aabb([-1.0, -1.0, -20.0], [1.0, 1.0, -9.0])));
+        assert!(!f.intersects_aabb(&aabb([40.0, -1.0, 0.0], [41.0, 1.0, 1.0])));
+
+        // contains_point agrees with clipping in homogeneous coordinates
+        for i in 0..100 {
+            let i = i as f32;
+            let p = Vector3f::new(
+                ((i * 7.3) % 30.0) - 15.0,
+                ((i * 3.1) % 20.0) - 10.0,
+                ((i * 13.7) % 120.0) - 15.0,
+            );
+            let c = view_proj * Vector4f::from_v3f(p, 1.0);
+            let inside = (c.x().abs() <= c.w())
+                && (c.y().abs() <= c.w())
+                && (c.z() >= 0.0)
+                && (c.z() <= c.w());
+            assert_eq!(inside, f.contains_point(p), "{p} {c}");
+        }
+    }
 
-        Self([c0, c1, c2, c3])
+    #[test]
+    fn integer_lerp_rounds_half_away_from_zero() {
+        assert_eq!(
+            Vector2i::ZERO.lerp(Vector2i::new(3, -3), 0.5),
+            Vector2i::new(2, -2)
+        );
+        assert_eq!(
+            Vector3i::ZERO
+                .lerp(Vector3i::new(4, 1, -1), 0.5)
+                .to_array4(),
+            [2, 1, -1, 0]
+        );
+        let a = Vector4i::new(1, 2, 3, 4);
+        let b = Vector4i::new(5, 6, 7, 8);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
     }
 
-    /// Linearily interpolates between this matrix and rhs
-    pub fn lerp(lhs: &Self, rhs: &Self, t: f32) -> Self {
-        let lhs_c0 = lhs.column(0);
-        let lhs_c1 = lhs.column(1);
-        let lhs_c2 = lhs.column(2);
-        let lhs_c3 = lhs.column(3);
+    #[test]
+    fn shear_and_inverse_shear() {
+        assert_eq!(
+            Matrix4x4::shear_xy(0.5) * Vector3f::new(1.0, 2.0, 3.0),
+            Vector3f::new(2.0, 2.0, 3.0)
+        );
 
-        let rhs_c0 = rhs.column(0);
-        let rhs_c1 = rhs.column(1);
-        let rhs_c2 = rhs.column(2);
-        let rhs_c3 = rhs.column(3);
+        let pairs = [
+            (Matrix4x4::shear_xy(0.5), Matrix4x4::shear_xy(-0.5)),
+            (Matrix4x4::shear_xz(0.7), Matrix4x4::shear_xz(-0.7)),
+            (Matrix4x4::shear_yx(1.1), Matrix4x4::shear_yx(-1.1)),
+            (Matrix4x4::shear_yz(-0.2), Matrix4x4::shear_yz(0.2)),
+            (Matrix4x4::shear_zx(0.9), Matrix4x4::shear_zx(-0.9)),
+            (Matrix4x4::shear_zy(-1.5), Matrix4x4::shear_zy(1.5)),
+        ];
+        for (a, b) in pairs {
+            assert!((a * b).is_identity(1e-6));
+        }
 
-        let t = f32x4::splat(t);
-        let c0 = lhs_c0 + ((rhs_c0 - lhs_c0) * t);
-        let c1 = lhs_c1 + ((rhs_c1 - lhs_c1) * t);
-        let c2 = lhs_c2 + ((rhs_c2 - lhs_c2) * t);
-        let c3 = lhs_c3 + ((rhs_c3 - lhs_c3) * t);
+        let s = Matrix4x4::shear(0.1, 0.2, 0.3, 0.4, 0.5, 0.6);
+        assert!((s * s.inverse()).is_identity(1e-5));
+    }
 
-        Self([c0, c1, c2, c3])
+    fn dot_many_points(n: usize) -> Vec<Vector4f> {
+        (0..n)
+            .map(|i| Vector4f::new(i as f32, ((i * 3) % 7) as f32, -(i as f32) * 0.5, 1.0))
+            .collect()
     }
 
-    /// Multiples the matrix with a vector while not applying translation
-    pub fn mul_no_translate(&self, rhs: Vector3f) -> Vector3f {
-        let c0 = self.column(0);
-        let c1 = self.column(1);
-        let c2 = self.column(2);
+    #[test]
+    fn dot_many_matches_dot() {
+        let plane = Vector4f::new(0.3, -1.2, 2.5, 0.7);
+        // lengths around the chunk size exercise the remainder loop
+        for n in [0, 1, 3, 4, 5, 17, 1000] {
+            let points = dot_many_points(n);
+            let mut out = vec![0.0; n];
+            plane.dot_many(&points, &mut out);
+            for (p, o) in points.iter().zip(&out) {
+                assert!((plane.dot(*p) - o).abs() <= 1e-3 * (1.0 + o.abs()));
+            }
+        }
+    }
 
-        let x = simd_swizzle_1!(rhs.0, 0);
-        let y = simd_swizzle_1!(rhs.0, 1);
-        let z = simd_swizzle_1!(rhs.0, 2);
-        Vector3f::from_simd_truncate((c0 * x) + (c1 * y) + (c2 * z))
+    #[bench]
+    fn bench_dot_many(bencher: &mut Bencher) {
+        let plane = Vector4f::new(0.3, -1.2, 2.5, 0.7);
+        let points = dot_many_points(4096);
+        let mut out = vec![0.0; points.len()];
+        bencher.iter(|| {
+            plane.dot_many(black_box(&points), &mut out);
+            black_box(&out);
+        });
     }
 
-    #[rustfmt::skip]
-    fn format_elements(&self) -> ([[String; 4]; 4], usize) {
-        let (s00, w00) = format_width!(self[(0, 0)]);
-        let (s10, w10) = format_width!(self[(1, 0)]);
-        let (s20, w20) = format_width!(self[(2, 0)]);
-        let (s30, w30) = format_width!(self[(3, 0)]);
-
-        let (s01, w01) = format_width!(self[(0, 1)]);
-        let (s11, w11) = format_width!(self[(1, 1)]);
-        let (s21, w21) = format_width!(self[(2, 1)]);
-        let (s31, w31) = format_width!(self[(3, 1)]);
-
-        let (s02, w02) = format_width!(self[(0, 2)]);
-        let (s12, w12) = format_width!(self[(1, 2)]);
-        let (s22, w22) = format_width!(self[(2, 2)]);
-        let (s32, w32) = format_width!(self[(3, 2)]);
-
-        let (s03, w03) = format_width!(self[(0, 3)]);
-        let (s13, w13) = format_width!(self[(1, 3)]);
-        let (s23, w23) = format_width!(self[(2, 3)]);
-        let (s33, w33) = format_width!(self[(3, 3)]);
+    #[bench]
+    fn bench_dot_many_scalar(bencher: &mut Bencher) {
+        let plane = Vector4f::new(0.3, -1.2, 2.5, 0.7);
+        let points = dot_many_points(4096);
+        let mut out = vec![0.0; points.len()];
+        bencher.iter(|| {
+            for (p, o) in black_box(&points).iter().zip(out.iter_mut()) {
+                *o = plane.dot(*p);
+            }
+            black_box(&out);
+        });
+    }
 
-        let strings = [
-            [s00, s10, s20, s30],
-            [s01, s11, s21, s31],
-            [s02, s12, s22, s32],
-            [s03, s13, s23, s33],
-        ];
+    #[test]
+    fn closest_point_and_distance_to_segment() {
+        let a = Vector3f::ZERO;
+        let b = Vector3f::new(2.0, 0.0, 0.0);
+        let p = Vector3f::new(-3.0, 4.0, 0.0);
 
-        let widths = [
-            w00, w10, w20, w30,
-            w01, w11, w21, w31,
-            w02, w12, w22, w32,
-            w03, w13, w23, w33,
-        ];
+        // midpoint, endpoints and beyond the endpoints
+        assert_eq!(
+            Vector3f::closest_point_on_segment(Vector3f::new(1.0, 3.0, 0.0), a, b),
+            Vector3f::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(Vector3f::closest_point_on_segment(a, a, b), a);
+        assert_eq!(Vector3f::closest_point_on_segment(b, a, b), b);
+        assert_eq!(Vector3f::closest_point_on_segment(p, a, b), a);
+        assert_eq!(Vector3f::distance_to_segment(p, a, b), 5.0);
+        assert_eq!(
+            Vector3f::distance_to_segment(Vector3f::new(2.0, 0.0, 1.0), a, b),
+            1.0
+        );
+        assert_eq!(Vector3f::distance_to_line(p, a, b), 4.0);
+        assert_eq!(
+            Vector3f::closest_point_on_line(p, a, b),
+            Vector3f::new(-3.0, 0.0, 0.0)
+        );
+
+        // zero length segments degenerate to the distance to their point
+        assert_eq!(
+            Vector3f::distance_to_segment(Vector3f::new(2.0, 0.0, 1.0), a, a),
+            5f32.sqrt()
+        );
+        assert_eq!(
+            Vector3f::distance_to_line(Vector3f::new(2.0, 0.0, 1.0), a, a),
+            5f32.sqrt()
+        );
+
+        let a = Vector2f::ZERO;
+        let b = Vector2f::new(2.0, 0.0);
+        assert_eq!(
+            Vector2f::distance_to_segment(Vector2f::new(1.0, 1.0), a, b),
+            1.0
+        );
+        assert_eq!(
+            Vector2f::distance_to_segment(Vector2f::new(5.0, 4.0), a, b),
+            5.0
+        );
+        assert_eq!(
+            Vector2f::closest_point_on_segment(Vector2f::new(5.0, 1.0), a, b),
+            b
+        );
+        assert_eq!(
+            Vector2f::closest_point_on_line(Vector2f::new(5.0, 1.0), a, b),
+            Vector2f::new(5.0, 0.0)
+        );
+        assert_eq!(
+            Vector2f::distance_to_segment(Vector2f::new(3.0, 4.0), a, a),
+            5.0
+        );
+    }
+
+    fn rotate_vectors_source(n: usize) -> Vec<Vector3f> {
+        (0..n)
+            .map(|i| Vector3f::new(i as f32, 1.0 - ((i as f32) * 0.3), 2.0))
+            .collect()
+    }
+
+    #[test]
+    fn rotate_vectors_matches_mul() {
+        let q = Quaternion::from_yaw_pitch_roll(0.4, -1.1, 2.3);
+        for n in [0, 1, 3, 4, 37] {
+            let src = rotate_vectors_source(n);
+            let mut dst = vec![Vector3f::ZERO; n];
+            q.rotate_vectors(&src, &mut dst);
+            for (s, d) in src.iter().zip(&dst) {
+                assert!((q * *s).abs_diff_eq(*d, 1e-4 * (1.0 + s.len())));
+                assert_eq!(d.to_array4()[3], 0.0);
+            }
+        }
+    }
 
-        (strings, widths.into_iter().max().unwrap())
+    #[bench]
+    fn bench_rotate_vectors(bencher: &mut Bencher) {
+        let q = Quaternion::from_yaw_pitch_roll(0.4, -1.1, 2.3);
+        let src = rotate_vectors_source(4096);
+        let mut dst = vec![Vector3f::ZERO; src.len()];
+        bencher.iter(|| {
+            q.rotate_vectors(black_box(&src), &mut dst);
+            black_box(&dst);
+        });
     }
 
-    /// Creates a matrix representing the transformation of looking from a position in a direction
-    pub fn look_to(pos: Vector3f, dir: Vector3f, up: Vector3f) -> Self {
-        let up = up.normalized();
+    #[bench]
+    fn bench_rotate_vectors_mul(bencher: &mut Bencher) {
+        let q = Quaternion::from_yaw_pitch_roll(0.4, -1.1, 2.3);
+        let src = rotate_vectors_source(4096);
+        let mut dst = vec![Vector3f::ZERO; src.len()];
+        bencher.iter(|| {
+            for (s, d) in black_box(&src).iter().zip(dst.iter_mut()) {
+                *d = q * *s;
+            }
+            black_box(&dst);
+        });
+    }
 
-        let f = dir.normalized();
-        let s = Vector3f::cross(up, f).normalized();
-        let u = Vector3f::cross(f, s);
+    #[test]
+    fn renormalize_rotation_restores_orthonormality() {
+        let step = Matrix4x4::rotation(Quaternion::from_yaw_pitch_roll(0.3, 0.2, 0.1));
+        let mut m = Matrix4x4::translation(Vector3f::new(1.0, 2.0, 3.0));
+        for _ in 0..10 {
+            m = m * step;
+        }
+        m[(0, 0)] += 0.01;
+        m[(1, 2)] -= 0.02;
+        m[(2, 1)] += 0.015;
+        assert!(!m.is_orthogonal(1e-3));
+
+        let drifted = m;
+        m.renormalize_rotation();
+        assert!(m.is_orthogonal(1e-5));
+        assert!((m.determinant() - 1.0).abs() < 1e-5);
+        assert_eq!(m.col(3), drifted.col(3));
+        assert!(m.abs_diff_eq(&drifted, 0.05));
+    }
+
+    #[test]
+    fn weighted_average_of_two_matches_nlerp() {
+        let a = Quaternion::from_yaw_pitch_roll(0.3, 0.2, 0.1);
+        // opposite hemisphere, so the average has to flip it
+        let b = -Quaternion::from_yaw_pitch_roll(1.3, -0.5, 0.4);
+        for t in [0.0, 0.25, 0.5, 0.9] {
+            let avg = Quaternion::weighted_average(&[a, b], &[1.0 - t, t]);
+            assert!(avg.abs_diff_eq(&a.nlerp(b, t), 1e-6), "{t} {avg}");
+        }
 
-        let tx = -Vector3f::dot(s, pos);
-        let ty = -Vector3f::dot(u, pos);
-        let tz = -Vector3f::dot(f, pos);
+        assert_eq!(Quaternion::weighted_average(&[], &[]), Quaternion::IDENTITY);
+        assert_eq!(
+            Quaternion::weighted_average(&[a], &[0.0]),
+            Quaternion::IDENTITY
+        );
+    }
 
-        Self::from_array([
-            [s.x(), u.x(), f.x(), 0.0],
-            [s.y(), u.y(), f.y(), 0.0],
-            [s.z(), u.z(), f.z(), 0.0],
-            [tx, ty, tz, 1.0],
-        ])
+    #[test]
+    #[should_panic]
+    fn weighted_average_length_mismatch() {
+        Quaternion::weighted_average(&[Quaternion::IDENTITY], &[]);
     }
 
-    /// Creates a matrix representing the transformation of looking from a position at a target
-    #[inline]
-    pub fn look_at(pos: Vector3f, target: Vector3f, up: Vector3f) -> Self {
-        Self::look_to(pos, target - pos, up)
+    #[test]
+    fn affine_determinant_matches_for_affine_inputs() {
+        let m = Matrix4x4::from_scale_rotation_translation(
+            Vector3f::new(2.0, -3.0, 0.5),
+            Quaternion::from_yaw_pitch_roll(0.3, 1.0, -0.4),
+            Vector3f::new(5.0, 6.0, 7.0),
+        );
+        assert!((m.affine_determinant() - m.determinant()).abs() < 1e-4);
+        assert!((m.affine_determinant() + 3.0).abs() < 1e-4);
+
+        // the bottom row is ignored, so projections disagree with the full determinant
+        let p = Matrix4x4::perspective(1.0, 1.0, 2.0, 10.0);
+        assert!((p.affine_determinant() - p.determinant()).abs() > 1e-3);
+    }
+
+    #[test]
+    fn rotation_between_uses_fallback_axis() {
+        let a = Vector3f::new(1.0, 2.0, -0.5);
+        let b = Vector3f::new(-3.0, 0.2, 1.0);
+        let q = Quaternion::rotation_between(a, b);
+        assert!((q * a.normalized()).abs_diff_eq(b.normalized(), 1e-5));
+        assert_eq!(
+            Quaternion::rotation_between(a, a * 3.0),
+            Quaternion::IDENTITY
+        );
+
+        // antiparallel, the fallback axis is made perpendicular and used for the half turn
+        let q = Quaternion::rotation_between_with_axis(
+            Vector3f::UNIT_X,
+            -Vector3f::UNIT_X * 2.0,
+            Vector3f::new(0.3, 1.0, 0.0),
+        );
+        let expected = Quaternion::from_axis_angle(Vector3f::UNIT_Y, std::f32::consts::PI);
+        assert!(q.abs_diff_eq_rotation(&expected, 1e-6));
+        assert!((q * Vector3f::UNIT_X).abs_diff_eq(-Vector3f::UNIT_X, 1e-5));
+
+        // a fallback axis parallel to the vectors can't be used
+        let q = Quaternion::rotation_between_with_axis(
+            Vector3f::UNIT_Z,
+            -Vector3f::UNIT_Z,
+            Vector3f::UNIT_Z,
+        );
+        assert!((q * Vector3f::UNIT_Z).abs_diff_eq(-Vector3f::UNIT_Z, 1e-5));
     }
 
-    /// Creates a perspective projection matrix
-    ///
-    /// Constraints:
-    /// - fov_y > 0.0
-    /// - aspect_ration > 0.0
-    /// - near_plane > 1.0
-    /// - far_plane > near_plane
-    #[rustfmt::skip]
-    pub fn perspective(fov_y: f32, aspect_ratio: f32, near_plane: f32, far_plane: f32) -> Self {
-        assert!(fov_y > 0.0);
-        assert!(aspect_ratio > 0.0);
-        assert!(near_plane > 1.0);
-        assert!(far_plane > near_plane);
+    #[test]
+    fn perspective_depth_ranges() {
+        let depth = |m: &Matrix4x4, z: f32| {
+            let c = *m * Vector4f::new(0.0, 0.0, z, 1.0);
+            c.z() / c.w()
+        };
 
-        let (sin, cos) = (fov_y * 0.5).sin_cos();
-        let h = cos / sin;
-        let w = h / aspect_ratio;
-        let r = far_plane / (far_plane - near_plane);
-        let z = -r * near_plane;
+        // [0, 1], OpenGL style [-1, 1] and reverse-Z [1, 0]
+        for (depth_min, depth_max) in [(0.0, 1.0), (-1.0, 1.0), (1.0, 0.0)] {
+            let m = Matrix4x4::perspective_with_depth(1.0, 1.3, 2.0, 50.0, depth_min, depth_max);
+            assert!((depth(&m, 2.0) - depth_min).abs() < 1e-5);
+            assert!((depth(&m, 50.0) - depth_max).abs() < 1e-5);
+        }
 
-        Self::from_array([
-            [ w , 0.0, 0.0, 0.0],
-            [0.0,  h , 0.0, 0.0],
-            [0.0, 0.0,  r , 1.0],
-            [0.0, 0.0,  z , 0.0]
-        ])
+        assert_eq!(
+            Matrix4x4::perspective(1.0, 1.3, 2.0, 50.0),
+            Matrix4x4::perspective_with_depth(1.0, 1.3, 2.0, 50.0, 0.0, 1.0)
+        );
     }
 
-    /// Creates an orthographic projection matrix
-    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32) -> Self {
-        let e00 = 2.0 / (right - left);
-        let e11 = 2.0 / (top - bottom);
-        let e03 = (right + left) / (left - right);
-        let e13 = (top + bottom) / (bottom - top);
+    #[test]
+    fn extend_and_truncate() {
+        let v = Vector2f::new(1.0, 2.0).extend(3.0).extend(4.0);
+        assert_eq!(v, Vector4f::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v.truncate(), Vector3f::new(1.0, 2.0, 3.0));
+        assert_eq!(v.truncate().truncate(), Vector2f::new(1.0, 2.0));
+        assert_eq!(v.truncate().to_array4()[3], 0.0);
 
-        Self::from_array([
-            [e00, 0.0, 0.0, 0.0],
-            [0.0, e11, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [e03, e13, 0.0, 1.0],
-        ])
+        let v = Vector2i::new(1, 2).extend(3).extend(4);
+        assert_eq!(v, Vector4i::new(1, 2, 3, 4));
+        assert_eq!(v.truncate().to_array4(), [1, 2, 3, 0]);
+        assert_eq!(v.truncate().truncate(), Vector2i::new(1, 2));
     }
 
-    /// Creates a centered orthographic projection matrix
-    pub fn orthographic_centered(width: f32, height: f32) -> Self {
-        let e00 = 2.0 / width;
-        let e11 = 2.0 / height;
+    #[test]
+    fn select_by_mixed_mask() {
+        let a = Vector3f::new(1.0, 5.0, -2.0);
+        let b = Vector3f::new(3.0, -1.0, -2.0);
 
-        Self::from_array([
-            [e00, 0.0, 0.0, 0.0],
-            [0.0, e11, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-}
-impl Index<(usize, usize)> for Matrix4x4 {
-    type Output = f32;
+        let lt = a.cmp_lt(b);
+        assert_eq!(lt, Vector3b::new(true, false, false));
+        assert_eq!(Vector3f::select(lt, a, b), Vector3f::new(1.0, -1.0, -2.0));
+        assert_eq!(
+            Vector3f::select(Vector3b::new(false, true, true), a, b),
+            Vector3f::new(3.0, 5.0, -2.0)
+        );
+        assert_eq!(Vector3f::select(lt, a, b).to_array4()[3], 0.0);
 
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        &self.0[index.1][index.0]
+        assert!(a.cmp_eq(a).all() && !a.cmp_ne(a).any());
+        assert!(a.cmp_le(a).all() && !a.cmp_gt(a).any());
+        assert_eq!(
+            Vector2i::new(1, 2).cmp_ge(Vector2i::new(1, 3)),
+            Vector2b::new(true, false)
+        );
+        assert_eq!(
+            Vector4u::select(
+                Vector4b::new(true, false, true, false),
+                Vector4u::ONE,
+                Vector4u::ZERO
+            ),
+            Vector4u::new(1, 0, 1, 0)
+        );
     }
-}
-impl IndexMut<(usize, usize)> for Matrix4x4 {
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        &mut self.0[index.1][index.0]
+
+    #[test]
+    fn infinity_constants_are_min_max_identities() {
+        let v = Vector3f::new(1.0, -2.0, 3.0);
+        assert_eq!(Vector3f::INFINITY.min(v), v);
+        assert_eq!(Vector3f::NEG_INFINITY.max(v), v);
+        assert_eq!(Vector2f::INFINITY.min(v.xy()), v.xy());
+        assert_eq!(Vector3f::NAN.to_array4()[3], 0.0);
+        assert!(Vector4f::NAN.to_array().iter().all(|c| c.is_nan()));
     }
-}
-impl Mul<Vector4f> for Matrix4x4 {
-    type Output = Vector4f;
 
-    fn mul(self, rhs: Vector4f) -> Self::Output {
-        let c0 = self.column(0);
-        let c1 = self.column(1);
-        let c2 = self.column(2);
-        let c3 = self.column(3);
+    #[test]
+    fn relative_to_recovers_child() {
+        let parent = Matrix4x4::from_scale_rotation_translation(
+            Vector3f::new(2.0, 0.5, 3.0),
+            Quaternion::from_yaw_pitch_roll(0.3, -0.7, 1.2),
+            Vector3f::new(1.0, -2.0, 5.0),
+        );
+        let child = Matrix4x4::from_scale_rotation_translation(
+            Vector3f::new(1.0, 1.5, 0.25),
+            Quaternion::from_yaw_pitch_roll(-1.3, 0.2, 0.4),
+            Vector3f::new(-4.0, 3.0, 2.0),
+        );
+        // affine fast path, sheared parent and projective parent
+        for parent in [
+            parent,
+            Matrix4x4::shear_xy(0.8) * parent,
+            Matrix4x4::perspective(1.0, 1.0, 2.0, 10.0),
+        ] {
+            let local = child.relative_to(&parent);
+            assert!((parent * local).abs_diff_eq(&child, 1e-4));
+        }
+    }
 
-        let x = simd_swizzle_1!(rhs.0, 0);
-        let y = simd_swizzle_1!(rhs.0, 1);
-        let z = simd_swizzle_1!(rhs.0, 2);
-        let w = simd_swizzle_1!(rhs.0, 3);
-        Vector4f((c0 * x) + (c1 * y) + (c2 * z) + (c3 * w))
+    #[test]
+    fn clamp_per_component() {
+        assert_eq!(
+            Vector3f::new(5.0, -2.0, 3.0).clamp(Vector3f::ZERO, Vector3f::ONE),
+            Vector3f::new(1.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            Vector2i::new(5, -2).clamp(Vector2i::ZERO, Vector2i::new(3, 3)),
+            Vector2i::new(3, 0)
+        );
+        assert_eq!(
+            Vector4f::new(0.5, 2.0, -1.0, 0.0).clamp(Vector4f::ZERO, Vector4f::ONE),
+            Vector4f::new(0.5, 1.0, 0.0, 0.0)
+        );
+        // min > max is unspecified but must not panic
+        let _ = Vector2f::ONE.clamp(Vector2f::ONE, Vector2f::ZERO);
     }
-}
-impl Mul<Vector3f> for Matrix4x4 {
-    type Output = Vector3f;
 
-    fn mul(self, rhs: Vector3f) -> Self::Output {
-        let c0 = self.column(0);
-        let c1 = self.column(1);
-        let c2 = self.column(2);
-        let c3 = self.column(3);
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_round_trip_with_padding() {
+        let vs = [
+            Vector3f::new(1.0, 2.0, 3.0),
+            Vector3f::new(4.0, 5.0, 6.0).recip(),
+        ];
+        let floats: &[f32] = bytemuck::cast_slice(&vs);
+        assert_eq!(floats.len(), 8);
+        assert_eq!(floats[3], 0.0);
+        assert_eq!(floats[7], 0.0);
+        let back: &[Vector3f] = bytemuck::cast_slice(floats);
+        assert_eq!(back, &vs);
+
+        let vs = [Vector3i::new(1, -2, 3), Vector3i::new(4, 5, 6) * 2];
+        let ints: &[i32] = bytemuck::cast_slice(&vs);
+        assert_eq!(ints, &[1, -2, 3, 0, 8, 10, 12, 0]);
+        let back: &[Vector3i] = bytemuck::cast_slice(ints);
+        assert_eq!(back, &vs);
+
+        let zeroed: Vector3f = bytemuck::Zeroable::zeroed();
+        assert_eq!(zeroed.to_array4(), [0.0; 4]);
+        let zeroed: Vector3i = bytemuck::Zeroable::zeroed();
+        assert_eq!(zeroed.to_array4(), [0; 4]);
+
+        let q = [Quaternion::IDENTITY];
+        assert_eq!(bytemuck::cast_slice::<_, f32>(&q), &[0.0, 0.0, 0.0, 1.0]);
+        let m = [Matrix4x4::IDENTITY];
+        assert_eq!(bytemuck::cast_slice::<_, f32>(&m).len(), 16);
+    }
+
+    #[test]
+    fn reflect_and_refract() {
+        let d = Vector3f::new(1.0, -1.0, 0.0).normalized();
+        let r = d.reflect(Vector3f::UNIT_Y);
+        assert!(r.abs_diff_eq(Vector3f::new(1.0, 1.0, 0.0).normalized(), 1e-6));
+        assert_eq!(
+            Vector2f::new(1.0, -1.0).reflect(Vector2f::UNIT_Y),
+            Vector2f::new(1.0, 1.0)
+        );
 
-        let x = simd_swizzle_1!(rhs.0, 0);
-        let y = simd_swizzle_1!(rhs.0, 1);
-        let z = simd_swizzle_1!(rhs.0, 2);
-        Vector3f::from_simd_truncate((c0 * x) + (c1 * y) + (c2 * z) + c3)
+        assert_eq!(d.refract(Vector3f::UNIT_Y, 1.0), d);
+        // from glass into air 45 degrees is past the critical angle of about 41.8 degrees
+        assert_eq!(d.refract(Vector3f::UNIT_Y, 1.5), Vector3f::ZERO);
+        let t = d.refract(Vector3f::UNIT_Y, 1.0 / 1.5);
+        assert!((t.len() - 1.0).abs() < 1e-6);
+        assert!((t.x() - (d.x() / 1.5)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn normalize_in_place_agrees_with_try_normalize() {
+        let mut v = Vector2f::new(3.0, 4.0);
+        assert!(v.normalize_in_place());
+        assert_eq!(v, Vector2f::new(0.6, 0.8));
+
+        for v in [
+            Vector3f::ZERO,
+            Vector3f::new(1e-20, 0.0, 0.0),
+            Vector3f::new(1e-7, 0.0, 0.0),
+            Vector3f::new(1e-3, 0.0, 0.0),
+            Vector3f::new(f32::MAX, f32::MAX, 0.0),
+            Vector3f::INFINITY,
+            Vector3f::NAN,
+        ] {
+            let mut w = v;
+            let normalized = w.normalize_in_place();
+            assert_eq!(normalized, v.try_normalize().is_some(), "{v}");
+            if normalized {
+                assert_eq!(Some(w), v.try_normalize());
+            } else {
+                assert_eq!(
+                    w.to_array4().map(f32::to_bits),
+                    v.to_array4().map(f32::to_bits)
+                );
+            }
+        }
     }
-}
-impl Mul for Matrix4x4 {
-    type Output = Self;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        let lhs_c0 = self.column(0);
-        let lhs_c1 = self.column(1);
-        let lhs_c2 = self.column(2);
-        let lhs_c3 = self.column(3);
+    #[test]
+    fn max_abs_element_catches_large_values() {
+        let mut m = Matrix4x4::IDENTITY;
+        m[(2, 3)] = -1.0e20;
+        assert_eq!(m.max_abs_element(), 1.0e20);
+        assert_eq!(m.abs()[(2, 3)], 1.0e20);
+        assert_eq!(m.abs()[(0, 0)], 1.0);
+        m[(1, 0)] = f32::NAN;
+        assert!(m.max_abs_element().is_nan());
 
-        let c0 = {
-            (lhs_c0 * f32x4::splat(rhs[(0, 0)]))
-                + (lhs_c1 * f32x4::splat(rhs[(1, 0)]))
-                + (lhs_c2 * f32x4::splat(rhs[(2, 0)]))
-                + (lhs_c3 * f32x4::splat(rhs[(3, 0)]))
-        };
-        let c1 = {
-            (lhs_c0 * f32x4::splat(rhs[(0, 1)]))
-                + (lhs_c1 * f32x4::splat(rhs[(1, 1)]))
-                + (lhs_c2 * f32x4::splat(rhs[(2, 1)]))
-                + (lhs_c3 * f32x4::splat(rhs[(3, 1)]))
-        };
-        let c2 = {
-            (lhs_c0 * f32x4::splat(rhs[(0, 2)]))
-                + (lhs_c1 * f32x4::splat(rhs[(1, 2)]))
-                + (lhs_c2 * f32x4::splat(rhs[(2, 2)]))
-                + (lhs_c3 * f32x4::splat(rhs[(3, 2)]))
-        };
-        let c3 = {
-            (lhs_c0 * f32x4::splat(rhs[(0, 3)]))
-                + (lhs_c1 * f32x4::splat(rhs[(1, 3)]))
-                + (lhs_c2 * f32x4::splat(rhs[(2, 3)]))
-                + (lhs_c3 * f32x4::splat(rhs[(3, 3)]))
-        };
+        let mut m = Matrix2x3::IDENTITY;
+        m[(1, 2)] = -7.0;
+        assert_eq!(m.max_abs_element(), 7.0);
+        assert_eq!(m.abs()[(1, 2)], 7.0);
+    }
 
-        Self([c0, c1, c2, c3])
+    #[test]
+    fn angle_to_is_signed() {
+        use std::f32::consts::*;
+
+        assert!((Vector2f::UNIT_X.angle_to(Vector2f::UNIT_Y) - FRAC_PI_2).abs() < 1e-6);
+        assert!((Vector2f::UNIT_Y.angle_to(Vector2f::UNIT_X) + FRAC_PI_2).abs() < 1e-6);
+        // half turns are always pi, never -pi
+        assert_eq!(Vector2f::UNIT_X.angle_to(-Vector2f::UNIT_X), PI);
+        assert_eq!((-Vector2f::UNIT_X).angle_to(Vector2f::UNIT_X), PI);
+        assert_eq!((-Vector2f::UNIT_Y).angle_to(Vector2f::UNIT_Y), PI);
     }
-}
-impl From<Matrix2x3> for Matrix4x4 {
-    fn from(other: Matrix2x3) -> Self {
-        other.to_matrix4x4()
+
+    #[test]
+    fn perspective_from_size_matches_aspect() {
+        assert_eq!(
+            Matrix4x4::perspective_from_size(1.0, 1920.0, 1080.0, 1.5, 100.0),
+            Matrix4x4::perspective(1.0, 1920.0 / 1080.0, 1.5, 100.0)
+        );
+        assert_eq!(
+            Matrix4x4::try_perspective_from_size(1.0, 1920.0, 0.0, 1.5, 100.0),
+            Err(ProjectionError::NonPositiveSize)
+        );
+        assert_eq!(
+            Matrix4x4::try_perspective_from_size(1.0, 0.0, 1080.0, 1.5, 100.0),
+            Err(ProjectionError::NonPositiveSize)
+        );
+        assert_eq!(
+            Matrix4x4::try_perspective_from_size(1.0, 1920.0, 1080.0, 0.5, 100.0),
+            Err(ProjectionError::NearPlaneTooClose)
+        );
     }
-}
-impl Debug for Matrix4x4 {
-    #[rustfmt::skip]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (strings, width) = self.format_elements();
-        let s = format!("Matrix4x4(\
-            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
-            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
-            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
-            \n\t{:<width$}, {:<width$}, {:<width$}, {:<width$},\
-            \n)",
-            strings[0][0], strings[1][0], strings[2][0], strings[3][0],
-            strings[0][1], strings[1][1], strings[2][1], strings[3][1],
-            strings[0][2], strings[1][2], strings[2][2], strings[3][2],
-            strings[0][3], strings[1][3], strings[2][3], strings[3][3],
-            width = width
+
+    #[test]
+    fn rotate_2d() {
+        use std::f32::consts::*;
+
+        assert!(Vector2f::UNIT_X
+            .rotate(FRAC_PI_2)
+            .abs_diff_eq(Vector2f::UNIT_Y, 1e-6));
+        assert!(Vector2f::new(2.0, 0.0)
+            .rotate(-FRAC_PI_2)
+            .abs_diff_eq(Vector2f::new(0.0, -2.0), 1e-6));
+
+        let dir = Vector2f::from_angle(FRAC_PI_4);
+        assert!(Vector2f::UNIT_X
+            .rotate_by(dir)
+            .rotate_by(dir)
+            .abs_diff_eq(Vector2f::UNIT_Y, 1e-6));
+    }
+
+    #[test]
+    fn integer_dot_and_cross_do_not_overflow() {
+        let a = Vector3i::new(100_000, -200_000, 300_000);
+        let b = Vector3i::new(-400_000, 500_000, 600_000);
+        assert_eq!(a.dot(b), 40_000_000_000);
+
+        let max = i32::MAX as i64;
+        let v = Vector4i::new(i32::MAX, 0, 0, 1);
+        assert_eq!(v.dot(v), (max * max) + 1);
+        assert_eq!(
+            Vector2i::new(i32::MAX, 3).cross(Vector2i::new(i32::MIN, 5)),
+            (max * 5) + (3 << 31)
         );
 
-        let s = s.replace('+', " ");
-        write!(f, "{}", s)
-    }
-}
-impl Display for Matrix4x4 {
-    #[rustfmt::skip]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (strings, width) = self.format_elements();
-        let s = format!("\
-            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|\n\
-            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|\n\
-            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|\n\
-            |{:<width$}   {:<width$}   {:<width$}   {:<width$}|",
-            strings[0][0], strings[1][0], strings[2][0], strings[3][0],
-            strings[0][1], strings[1][1], strings[2][1], strings[3][1],
-            strings[0][2], strings[1][2], strings[2][2], strings[3][2],
-            strings[0][3], strings[1][3], strings[2][3], strings[3][3],
-            width = width
+        let c = Vector3i::new(30_000, -20_000, 10_000);
+        let d = Vector3i::new(-5_000, 25_000, 32_000);
+        let e = c.cross(d);
+        assert_eq!(e, Vector3i::new(-890_000_000, -1_010_000_000, 650_000_000));
+        assert_eq!(e.dot(c), 0);
+        assert_eq!(e.dot(d), 0);
+    }
+
+    #[test]
+    fn perpendiculars() {
+        assert_eq!(Vector2f::UNIT_X.perp(), Vector2f::UNIT_Y);
+        assert_eq!(Vector2f::UNIT_X.perp_cw(), -Vector2f::UNIT_Y);
+        assert_eq!(Vector2f::new(2.0, 3.0).perp(), Vector2f::new(-3.0, 2.0));
+        assert_eq!(Vector2f::new(2.0, 3.0).perp_cw(), Vector2f::new(3.0, -2.0));
+    }
+
+    #[test]
+    fn slerp_normalizes_inputs() {
+        let a = Quaternion::IDENTITY;
+        let b = Quaternion::from_angle_z(1.0);
+        let r = (a * 3.0).slerp(b * 0.5, 0.25);
+        assert!(r.abs_diff_eq(&Quaternion::from_angle_z(0.25), 1e-5), "{r}");
+
+        // slightly scaled copies of the same rotation push the raw dot product above 1
+        let r = (a * 1.001).slerp(a * 1.002, 0.5);
+        assert!(!r.x().is_nan() && !r.w().is_nan());
+        assert!(r.abs_diff_eq(&a, 1e-5), "{r}");
+    }
+
+    #[test]
+    fn try_normalize_and_normalize_or_zero() {
+        assert_eq!(Vector3f::ZERO.try_normalize(), None);
+        assert_eq!(Vector3f::ZERO.normalize_or_zero(), Vector3f::ZERO);
+        assert_eq!(Vector2f::new(1e-8, 0.0).try_normalize(), None);
+        assert_eq!(Vector4f::NAN.normalize_or_zero(), Vector4f::ZERO);
+
+        let v = Vector3f::new(3.0, 0.0, 4.0);
+        let n = v.try_normalize().unwrap();
+        assert!(n.abs_diff_eq(Vector3f::new(0.6, 0.0, 0.8), 1e-6));
+        assert!((n * v.len()).abs_diff_eq(v, 1e-5));
+        assert_eq!(v.normalize_or_zero(), n);
+    }
+
+    #[test]
+    fn try_from_iter_checks_length() {
+        assert_eq!(
+            Vector3f::try_from_iter([1.0, 2.0]),
+            Err(LengthError::TooFew {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Vector3f::try_from_iter([1.0, 2.0, 3.0]),
+            Ok(Vector3f::new(1.0, 2.0, 3.0))
+        );
+        assert_eq!(
+            Vector3f::try_from_iter([1.0, 2.0, 3.0, 4.0]),
+            Err(LengthError::TooMany { expected: 3 })
+        );
+        // only one element past the end is consumed, so endless iterators are fine
+        assert_eq!(
+            Vector3f::try_from_iter(std::iter::repeat(1.0)),
+            Err(LengthError::TooMany { expected: 3 })
         );
+        assert_eq!(Vector2u::try_from_iter(0..2), Ok(Vector2u::new(0, 1)));
+    }
 
-        let s = s.replace('+', " ");
-        write!(f, "{}", s)
+    #[test]
+    fn rotate_toward_opposite_turns_counter_clockwise() {
+        // the cross product of (-1, 0) and (1, 0) is -0
+        let r = Vector2f::new(-1.0, 0.0).rotate_toward(Vector2f::new(1.0, 0.0), 0.25);
+        assert!(r.y() < 0.0);
+        assert!((r.to_angle() + std::f32::consts::PI - 0.25).abs() < 1e-6);
+
+        for i in 0..16 {
+            let a = Vector2f::from_angle((i as f32) * std::f32::consts::FRAC_PI_8);
+            let r = a.rotate_toward(-a, 0.25);
+            assert!(a.cross(r) > 0.0, "{a}");
+        }
     }
-}
 
-#[cfg(feature = "bytemuck")]
-use bytemuck::{Pod, Zeroable};
+    #[test]
+    fn quaternion_from_rotation_matrix_round_trip() {
+        for i in 0..200 {
+            let a = (i as f32) * 0.37 - 30.0;
+            let q = Quaternion::from_yaw_pitch_roll(a, a * 0.7 + 1.0, a * 1.3 - 0.5);
+            let r = Quaternion::from(Matrix4x4::rotation(q));
+            assert!(r.abs_diff_eq_rotation(&q, 1e-4), "{q} {r}");
+        }
 
-macro_rules! impl_bytemuck {
-    ($t:ty) => {
-        #[cfg(feature = "bytemuck")]
-        unsafe impl Pod for $t {}
-        #[cfg(feature = "bytemuck")]
-        unsafe impl Zeroable for $t {}
-    };
-}
+        // the identity takes the positive trace branch, rotations of about pi
+        // make the x, y and z diagonal element the largest one in turn
+        let axes = [Vector3f::UNIT_X, Vector3f::UNIT_Y, Vector3f::UNIT_Z];
+        let mut qs = vec![Quaternion::IDENTITY];
+        for axis in axes {
+            qs.push(Quaternion::from_axis_angle(axis, std::f32::consts::PI));
+            qs.push(Quaternion::from_axis_angle(axis, 3.0));
+            qs.push(Quaternion::from_axis_angle(
+                (axis + Vector3f::ONE * 0.1).normalized(),
+                3.1,
+            ));
+        }
+        for q in qs {
+            let r = Quaternion::from(Matrix4x4::rotation(q));
+            assert!(r.abs_diff_eq_rotation(&q, 1e-4), "{q} {r}");
+        }
+    }
 
-impl_bytemuck!(Vector2f);
-impl_bytemuck!(Vector3f);
-impl_bytemuck!(Vector4f);
-impl_bytemuck!(Vector2i);
-impl_bytemuck!(Vector3i);
-impl_bytemuck!(Vector4i);
-impl_bytemuck!(Quaternion);
-impl_bytemuck!(Matrix2x3);
-impl_bytemuck!(Matrix4x4);
+    #[test]
+    fn add_scaled_matches_elementwise() {
+        let mut seed = 11;
+        for _ in 0..20 {
+            let mut m = random_matrix(&mut seed);
+            let o = random_matrix(&mut seed);
+            let s = random_f32(&mut seed);
+
+            let orig = m;
+            m.add_scaled(&o, s);
+            for r in 0..4 {
+                for c in 0..4 {
+                    let expected = orig[(r, c)] + o[(r, c)] * s;
+                    assert!((m[(r, c)] - expected).abs() < 1e-6, "({r}, {c})");
+                }
+            }
+        }
+    }
 
-#[allow(non_camel_case_types)]
-#[cfg(feature = "short_names")]
-mod short_names {
-    use super::*;
+    #[test]
+    fn to_axis_angle_of_denormalized_quaternions() {
+        let mut seed = 3;
+        for _ in 0..1000 {
+            let q = Quaternion::new(
+                random_f32(&mut seed),
+                random_f32(&mut seed),
+                random_f32(&mut seed),
+                random_f32(&mut seed),
+            );
+            let q = q.normalized() * (1.0 + random_f32(&mut seed) * 1e-3);
+
+            let (axis, angle) = q.to_axis_angle();
+            assert!(axis.to_array().iter().all(|c| c.is_finite()), "{q}");
+            assert!((axis.len() - 1.0).abs() < 1e-3, "{q}");
+            assert!((0.0..=std::f32::consts::PI).contains(&angle), "{q}");
+            assert!(Quaternion::from_axis_angle(axis, angle)
+                .abs_diff_eq_rotation(&q.normalized(), 1e-3));
+        }
 
-    /// A vector with 2 f32 components
-    pub type v2f = Vector2f;
-    /// A vector with 3 f32 components
-    pub type v3f = Vector3f;
-    /// A vector with 4 f32 components
-    pub type v4f = Vector4f;
+        // the negative double cover of the identity, slightly too long
+        for w in [-1.0, -1.0000001, -1.001] {
+            let (axis, angle) = Quaternion::new(0.0, 0.0, 0.0, w).to_axis_angle();
+            assert!(axis.to_array().iter().all(|c| c.is_finite()));
+            assert_eq!(angle, 0.0);
+        }
+    }
 
-    /// A vector with 2 i32 components
-    pub type v2i = Vector2i;
-    /// A vector with 3 i32 components
-    pub type v3i = Vector3i;
-    /// A vector with 4 i32 components
-    pub type v4i = Vector4i;
+    #[test]
+    fn product_of_vectors() {
+        let a = Vector3f::new(1.0, -2.0, 3.0);
+        let b = Vector3f::new(2.0, 2.0, -0.5);
+        let p = [a, b].iter().product::<Vector3f>();
+        assert_eq!(p, Vector3f::new(2.0, -4.0, -1.5));
+        assert_eq!(p.to_array4(), [2.0, -4.0, -1.5, 0.0]);
+        assert_eq!([a, b].into_iter().product::<Vector3f>(), p);
 
-    /// A quaternion
-    pub type quat = Quaternion;
-    /// Column-major 2x3 matrix, indexed as [row, column]
-    pub type mat3 = Matrix2x3;
-    /// Column-major 4x4 matrix, indexed as [row, column]
-    pub type mat4 = Matrix4x4;
-}
+        let p = [Vector3i::new(1, 2, 3), Vector3i::new(-1, 4, 2)]
+            .iter()
+            .product::<Vector3i>();
+        assert_eq!(p.to_array4(), [-1, 8, 6, 0]);
+    }
 
-#[cfg(feature = "short_names")]
-pub use short_names::*;
+    #[test]
+    fn vector3u_ignores_padding_in_eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
 
-include!(concat!(env!("OUT_DIR"), "/swizzle.rs"));
+        let hash = |v: Vector3u| {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let v = Vector3u::new(1, 2, 3);
+        let padded = Vector3u(u32x4::from_array([1, 2, 3, 7]));
+        assert_eq!(v, padded);
+        assert_eq!(hash(v), hash(padded));
+        assert_ne!(v, Vector3u::new(1, 2, 4));
+    }
+
+    #[test]
+    fn try_inverse_threshold_is_relative() {
+        let m = Matrix4x4::scaling(Vector3f::new(0.01, 0.01, 0.01));
+        let inv = m.try_inverse().unwrap();
+        assert!((m * inv).abs_diff_eq(&Matrix4x4::IDENTITY, 1e-5));
+
+        let m = Matrix4x4::scaling(Vector3f::new(1e3, 1e3, 1e3));
+        assert!(m.try_inverse().is_some());
+
+        assert!(Matrix4x4::scaling(Vector3f::new(1.0, 0.0, 1.0))
+            .try_inverse()
+            .is_none());
+        // singular with large elements, the third column is the sum of the first two
+        let m = Matrix4x4::from_array([
+            [100.0, 0.0, 0.0, 0.0],
+            [0.0, 100.0, 0.0, 0.0],
+            [100.0, 100.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        assert!(m.try_inverse().is_none());
+    }
+
+    #[test]
+    fn matrix2x3_display() {
+        let m = Matrix2x3::new(1.0, 4.0, -2.0, 5.0, 3.0, -6.0);
+        assert_eq!(
+            format!("{m}"),
+            "| 1   -2    3|\n| 4    5   -6|\n|0    0    1 |"
+        );
+        assert_eq!(
+            format!("{m:.1}"),
+            "| 1.0   -2.0    3.0|\n| 4.0    5.0   -6.0|\n|0.0    0.0    1.0 |"
+        );
+    }
+}